@@ -0,0 +1,425 @@
+//! Generates the CPI bindings of the Pump.fun program from `idl.json`.
+//!
+//! The IDL uses the Anchor 0.30 format, which `anchor-gen` cannot read. This script emits the same
+//! Anchor program skeleton `anchor-gen` would, so the `#[program]`, `#[derive(Accounts)]`,
+//! `#[account]`, `#[event]` and `#[error_code]` macros produce the CPI interface. On top of that it
+//! emits constants for the declared PDA seeds, resolvers deriving the PDAs, and compile-time checks
+//! that the discriminators declared in the IDL match the ones Anchor computes.
+
+use serde_json::Value;
+use std::{collections::BTreeMap, env, fmt::Write, fs, path::Path};
+
+const IDL: &str = "idl.json";
+
+fn main() {
+    println!("cargo:rerun-if-changed={}", IDL);
+    println!("cargo:rerun-if-changed=build.rs");
+
+    let idl: Value = serde_json::from_str(&fs::read_to_string(IDL).expect("failed to read IDL"))
+        .expect("failed to parse IDL");
+
+    let mut out = String::new();
+    writeln!(out, "// @generated by build.rs from {}. Do not edit.", IDL).unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "use anchor_lang::prelude::*;").unwrap();
+    writeln!(out).unwrap();
+    writeln!(out, "declare_id!(\"{}\");", str_field(&idl, "address")).unwrap();
+    writeln!(out).unwrap();
+
+    generate_program(&mut out, &idl);
+    generate_accounts_structs(&mut out, &idl);
+    generate_types(&mut out, &idl);
+    generate_errors(&mut out, &idl);
+    generate_seeds(&mut out, &idl);
+    generate_discriminator_checks(&mut out, &idl);
+
+    let path = Path::new(&env::var("OUT_DIR").unwrap()).join("pump.rs");
+    fs::write(path, out).expect("failed to write bindings");
+}
+
+/// Emits the program module with one stub handler per instruction
+fn generate_program(out: &mut String, idl: &Value) {
+    let name = str_field(&idl["metadata"], "name");
+
+    writeln!(out, "#[program]").unwrap();
+    writeln!(out, "pub mod {} {{", to_snake_case(name)).unwrap();
+    writeln!(out, "    use super::*;").unwrap();
+
+    for instruction in array(idl, "instructions") {
+        let name = str_field(instruction, "name");
+        writeln!(out).unwrap();
+        write_docs(out, instruction, "    ");
+        // Anchor derives the instruction discriminator from the handler name, so it is kept
+        // exactly as declared by the program, e.g. `setParams`
+        write!(
+            out,
+            "    pub fn {}(_ctx: Context<{}>",
+            name,
+            to_pascal_case(name)
+        )
+        .unwrap();
+        for arg in array(instruction, "args") {
+            write!(
+                out,
+                ", _{}: {}",
+                to_snake_case(str_field(arg, "name")),
+                rust_type(&arg["type"])
+            )
+            .unwrap();
+        }
+        writeln!(out, ") -> Result<()> {{").unwrap();
+        // Handlers are never executed, the program only exists to generate the CPI client
+        writeln!(out, "        Ok(())").unwrap();
+        writeln!(out, "    }}").unwrap();
+    }
+
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+}
+
+/// Emits the accounts struct of every instruction
+fn generate_accounts_structs(out: &mut String, idl: &Value) {
+    for instruction in array(idl, "instructions") {
+        writeln!(out, "#[derive(Accounts)]").unwrap();
+        writeln!(
+            out,
+            "pub struct {}<'info> {{",
+            to_pascal_case(str_field(instruction, "name"))
+        )
+        .unwrap();
+
+        for account in array(instruction, "accounts") {
+            let writable = account["writable"].as_bool().unwrap_or(false);
+            let signer = account["signer"].as_bool().unwrap_or(false);
+
+            write_docs(out, account, "    ");
+            writeln!(out, "    /// CHECK: Validated by the program").unwrap();
+            if writable {
+                writeln!(out, "    #[account(mut)]").unwrap();
+            }
+            writeln!(
+                out,
+                "    pub {}: {}<'info>,",
+                to_snake_case(str_field(account, "name")),
+                if signer { "Signer" } else { "AccountInfo" }
+            )
+            .unwrap();
+        }
+
+        writeln!(out, "}}").unwrap();
+        writeln!(out).unwrap();
+    }
+}
+
+/// Emits the type definitions, marking accounts and events with their Anchor attributes
+fn generate_types(out: &mut String, idl: &Value) {
+    let names = |key: &str| -> Vec<&str> {
+        array(idl, key)
+            .iter()
+            .map(|item| str_field(item, "name"))
+            .collect()
+    };
+    let accounts = names("accounts");
+    let events = names("events");
+
+    for definition in array(idl, "types") {
+        let name = str_field(definition, "name");
+        let ty = &definition["type"];
+
+        write_docs(out, definition, "");
+        if accounts.contains(&name) {
+            writeln!(out, "#[account]").unwrap();
+            writeln!(out, "#[derive(Debug, Default)]").unwrap();
+        } else if events.contains(&name) {
+            writeln!(out, "#[event]").unwrap();
+            writeln!(out, "#[derive(Debug, Clone)]").unwrap();
+        } else {
+            writeln!(
+                out,
+                "#[derive(AnchorSerialize, AnchorDeserialize, Debug, Clone)]"
+            )
+            .unwrap();
+        }
+
+        match str_field(ty, "kind") {
+            "struct" => {
+                writeln!(out, "pub struct {} {{", name).unwrap();
+                for field in array(ty, "fields") {
+                    write_docs(out, field, "    ");
+                    writeln!(
+                        out,
+                        "    pub {}: {},",
+                        to_snake_case(str_field(field, "name")),
+                        rust_type(&field["type"])
+                    )
+                    .unwrap();
+                }
+                writeln!(out, "}}").unwrap();
+            }
+            "enum" => {
+                writeln!(out, "pub enum {} {{", name).unwrap();
+                for variant in array(ty, "variants") {
+                    if variant.get("fields").is_some() {
+                        panic!("enum variants with fields are not supported: {}", name);
+                    }
+                    writeln!(out, "    {},", str_field(variant, "name")).unwrap();
+                }
+                writeln!(out, "}}").unwrap();
+            }
+            kind => panic!("unsupported type kind {} of {}", kind, name),
+        }
+        writeln!(out).unwrap();
+    }
+}
+
+/// Emits the program's error codes
+fn generate_errors(out: &mut String, idl: &Value) {
+    let errors = array(idl, "errors");
+    let Some(first) = errors.first() else {
+        return;
+    };
+    let offset = first["code"].as_u64().expect("error code");
+
+    writeln!(out, "#[error_code(offset = {})]", offset).unwrap();
+    writeln!(out, "pub enum ErrorCode {{").unwrap();
+    for (index, error) in errors.iter().enumerate() {
+        let code = error["code"].as_u64().expect("error code");
+        assert_eq!(
+            code,
+            offset + index as u64,
+            "error codes must be contiguous"
+        );
+
+        if let Some(msg) = error["msg"].as_str() {
+            writeln!(out, "    #[msg({:?})]", msg).unwrap();
+        }
+        writeln!(out, "    {},", str_field(error, "name")).unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+}
+
+/// Emits constants for the PDA seeds declared by the instructions and resolvers deriving the PDAs
+fn generate_seeds(out: &mut String, idl: &Value) {
+    // PDAs keyed by account name, with their constant seeds and the accounts they are derived from
+    let mut pdas: BTreeMap<String, (Vec<Vec<u8>>, Vec<String>)> = BTreeMap::new();
+    for instruction in array(idl, "instructions") {
+        for account in array(instruction, "accounts") {
+            let Some(pda) = account.get("pda") else {
+                continue;
+            };
+
+            let mut constants = Vec::new();
+            let mut paths = Vec::new();
+            for seed in array(pda, "seeds") {
+                match str_field(seed, "kind") {
+                    "const" => constants.push(
+                        array(seed, "value")
+                            .iter()
+                            .map(|byte| byte.as_u64().expect("seed byte") as u8)
+                            .collect(),
+                    ),
+                    "account" => paths.push(to_snake_case(str_field(seed, "path"))),
+                    kind => panic!("unsupported seed kind {}", kind),
+                }
+            }
+
+            let name = to_snake_case(str_field(account, "name"));
+            let seeds = (constants, paths);
+            if let Some(existing) = pdas.get(&name) {
+                assert_eq!(*existing, seeds, "conflicting seeds for {}", name);
+            }
+            pdas.insert(name, seeds);
+        }
+    }
+
+    writeln!(
+        out,
+        "/// Constant seeds of the PDAs declared by the program"
+    )
+    .unwrap();
+    writeln!(out, "pub mod seeds {{").unwrap();
+    for (name, (constants, _)) in &pdas {
+        let bytes = constants.concat();
+        writeln!(out, "    /// Seed for the `{}` PDA", name).unwrap();
+        writeln!(
+            out,
+            "    pub const {}_SEED: &[u8] = b\"{}\";",
+            name.to_uppercase(),
+            bytes.escape_ascii()
+        )
+        .unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    writeln!(
+        out,
+        "/// Resolvers deriving the PDAs declared by the program"
+    )
+    .unwrap();
+    writeln!(out, "pub mod pda {{").unwrap();
+    writeln!(out, "    use anchor_lang::prelude::Pubkey;").unwrap();
+    for (name, (constants, paths)) in &pdas {
+        if constants.len() != 1 {
+            panic!("PDA {} must have exactly one constant seed", name);
+        }
+
+        writeln!(out).unwrap();
+        writeln!(
+            out,
+            "    /// Finds the `{}` PDA and its bump for the given program",
+            name
+        )
+        .unwrap();
+        write!(out, "    pub fn find_{}(", name).unwrap();
+        for path in paths {
+            write!(out, "{}: &Pubkey, ", path).unwrap();
+        }
+        writeln!(out, "program_id: &Pubkey) -> (Pubkey, u8) {{").unwrap();
+        write!(
+            out,
+            "        Pubkey::find_program_address(&[super::seeds::{}_SEED",
+            name.to_uppercase()
+        )
+        .unwrap();
+        for path in paths {
+            write!(out, ", {}.as_ref()", path).unwrap();
+        }
+        writeln!(out, "], program_id)").unwrap();
+        writeln!(out, "    }}").unwrap();
+    }
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+}
+
+/// Emits compile-time checks that the IDL discriminators match the ones Anchor computes
+fn generate_discriminator_checks(out: &mut String, idl: &Value) {
+    writeln!(
+        out,
+        "const fn discriminator_eq(a: [u8; 8], b: [u8; 8]) -> bool {{"
+    )
+    .unwrap();
+    writeln!(out, "    let mut i = 0;").unwrap();
+    writeln!(out, "    while i < 8 {{").unwrap();
+    writeln!(out, "        if a[i] != b[i] {{").unwrap();
+    writeln!(out, "            return false;").unwrap();
+    writeln!(out, "        }}").unwrap();
+    writeln!(out, "        i += 1;").unwrap();
+    writeln!(out, "    }}").unwrap();
+    writeln!(out, "    true").unwrap();
+    writeln!(out, "}}").unwrap();
+    writeln!(out).unwrap();
+
+    let mut check = |path: String, item: &Value| {
+        let discriminator: Vec<String> = array(item, "discriminator")
+            .iter()
+            .map(|byte| byte.as_u64().expect("discriminator byte").to_string())
+            .collect();
+        writeln!(
+            out,
+            "const _: () = assert!(discriminator_eq(<{} as anchor_lang::Discriminator>::DISCRIMINATOR, [{}]));",
+            path,
+            discriminator.join(", ")
+        )
+        .unwrap();
+    };
+
+    for instruction in array(idl, "instructions") {
+        check(
+            format!(
+                "instruction::{}",
+                to_pascal_case(str_field(instruction, "name"))
+            ),
+            instruction,
+        );
+    }
+    for item in array(idl, "accounts")
+        .iter()
+        .chain(array(idl, "events").iter())
+    {
+        check(str_field(item, "name").to_string(), item);
+    }
+}
+
+/// Maps an IDL type to a Rust type
+fn rust_type(ty: &Value) -> String {
+    if let Some(name) = ty.as_str() {
+        return match name {
+            "bool" | "u8" | "i8" | "u16" | "i16" | "u32" | "i32" | "u64" | "i64" | "u128"
+            | "i128" | "f32" | "f64" => name.to_string(),
+            "string" => "String".to_string(),
+            "pubkey" => "Pubkey".to_string(),
+            "bytes" => "Vec<u8>".to_string(),
+            _ => panic!("unsupported type {}", name),
+        };
+    }
+
+    if let Some(inner) = ty.get("vec") {
+        format!("Vec<{}>", rust_type(inner))
+    } else if let Some(inner) = ty.get("option") {
+        format!("Option<{}>", rust_type(inner))
+    } else if let Some(array) = ty.get("array") {
+        format!(
+            "[{}; {}]",
+            rust_type(&array[0]),
+            array[1].as_u64().expect("array length")
+        )
+    } else if let Some(defined) = ty.get("defined") {
+        defined
+            .as_str()
+            .unwrap_or_else(|| str_field(defined, "name"))
+            .to_string()
+    } else {
+        panic!("unsupported type {}", ty)
+    }
+}
+
+/// Writes the IDL docs of an item as doc comments
+fn write_docs(out: &mut String, item: &Value, indent: &str) {
+    for doc in array(item, "docs") {
+        writeln!(out, "{}/// {}", indent, doc.as_str().expect("doc")).unwrap();
+    }
+}
+
+fn array<'a>(value: &'a Value, key: &str) -> &'a [Value] {
+    value
+        .get(key)
+        .and_then(Value::as_array)
+        .map_or(&[], Vec::as_slice)
+}
+
+fn str_field<'a>(value: &'a Value, key: &str) -> &'a str {
+    value[key]
+        .as_str()
+        .unwrap_or_else(|| panic!("missing {} in {}", key, value))
+}
+
+/// Converts camelCase or snake_case to snake_case
+fn to_snake_case(name: &str) -> String {
+    let mut out = String::new();
+    for c in name.chars() {
+        if c.is_ascii_uppercase() {
+            if !out.is_empty() && !out.ends_with('_') {
+                out.push('_');
+            }
+            out.push(c.to_ascii_lowercase());
+        } else {
+            out.push(c);
+        }
+    }
+    out
+}
+
+/// Converts camelCase or snake_case to PascalCase
+fn to_pascal_case(name: &str) -> String {
+    to_snake_case(name)
+        .split('_')
+        .map(|part| {
+            let mut chars = part.chars();
+            chars.next().map_or(String::new(), |first| {
+                first.to_ascii_uppercase().to_string() + chars.as_str()
+            })
+        })
+        .collect()
+}
@@ -1,3 +1,6 @@
-anchor_gen::generate_cpi_crate!("idl.classic.json");
+//! CPI bindings for the Pump.fun program, generated from `idl.json` by the build script.
 
-declare_id!("6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P");
+// Handlers keep the IDL's instruction names, which determine their discriminators
+#![allow(non_snake_case)]
+
+include!(concat!(env!("OUT_DIR"), "/pump.rs"));
@@ -20,12 +20,48 @@
 //!
 //! - `new`: Creates a new bonding curve instance
 //! - `get_buy_price`: Calculates the amount of tokens received for a given SOL amount
+//! - `get_buy_sol_cost`: Calculates the amount of SOL required to buy a given token amount
 //! - `get_sell_price`: Calculates the amount of SOL received for selling tokens
 //! - `get_market_cap_sol`: Calculates the current market cap in SOL
 //! - `get_final_market_cap_sol`: Calculates the final market cap in SOL after all tokens are sold
 //! - `get_buy_out_price`: Calculates the price to buy out all remaining tokens
-
+//! - `apply_buy`: Applies a buy to the reserves like the on-chain program
+//! - `apply_sell`: Applies a sell to the reserves like the on-chain program
+//! - `apply_trade_event`: Syncs the reserves from a trade event
+//! - `try_from_account_data`: Deserializes the account after validating its discriminator
+//!
+//! # Layouts
+//!
+//! Newer program versions append a creator to the account. `VersionedBondingCurveAccount`
+//! decodes either layout, exposing the creator as `BondingCurveAccountV2` when present.
+//!
+//! # Filtering
+//!
+//! `BondingCurveFilter` selects bonding curves when enumerating the program's accounts, building
+//! the `getProgramAccounts` filters for the conditions the RPC node can evaluate.
+
+use super::deserialize_account;
+use crate::{
+    error::{ClientError, CurveError},
+    events::TradeEvent,
+};
+use anchor_client::{
+    solana_client::rpc_filter::{Memcmp, RpcFilterType},
+    solana_sdk::pubkey::Pubkey,
+};
 use borsh::{BorshDeserialize, BorshSerialize};
+use serde::{Deserialize, Serialize};
+
+/// Amounts exchanged by a trade applied to a bonding curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct CurveTrade {
+    /// Amount of tokens bought or sold in base units
+    pub token_amount: u64,
+    /// Amount of SOL added to or removed from the curve in lamports, excluding fees
+    pub sol_amount: u64,
+    /// Protocol fee in lamports, paid on top of buys and deducted from sells
+    pub fee: u64,
+}
 
 /// Represents a bonding curve for token pricing and liquidity management
 #[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
@@ -47,6 +83,27 @@ pub struct BondingCurveAccount {
 }
 
 impl BondingCurveAccount {
+    /// Anchor discriminator of the account, the first 8 bytes of `sha256("account:BondingCurve")`
+    pub const DISCRIMINATOR: [u8; 8] = [23, 183, 248, 55, 96, 216, 172, 96];
+
+    /// Size of the original account layout in bytes
+    pub const LEN: usize = 8 + 5 * 8 + 1;
+
+    /// Deserializes a bonding curve from raw account data
+    ///
+    /// Validates the Anchor discriminator and ignores fields appended by newer program versions.
+    ///
+    /// # Arguments
+    /// * `data` - Raw account data
+    ///
+    /// # Returns
+    /// * `Ok(BondingCurveAccount)` - The deserialized account
+    /// * `Err(ClientError)` - Error if the data is not a bonding curve account or is too short
+    #[allow(clippy::result_large_err)]
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self, ClientError> {
+        deserialize_account(data, Self::DISCRIMINATOR, "BondingCurve")
+    }
+
     /// Creates a new bonding curve instance
     ///
     /// # Arguments
@@ -84,11 +141,9 @@ impl BondingCurveAccount {
     ///
     /// # Returns
     /// * `Ok(u64)` - Amount of tokens that would be received
-    /// * `Err(&str)` - Error message if curve is complete
-    pub fn get_buy_price(&self, amount: u64) -> Result<u64, &'static str> {
-        if self.complete {
-            return Err("Curve is complete");
-        }
+    /// * `Err(CurveError)` - Error if the curve is complete or has empty reserves
+    pub fn get_buy_price(&self, amount: u64) -> Result<u64, CurveError> {
+        self.check_tradable()?;
 
         if amount == 0 {
             return Ok(0);
@@ -104,15 +159,44 @@ impl BondingCurveAccount {
         let r: u128 = n / i + 1;
 
         // Calculate the amount of tokens to be purchased
-        let s: u128 = (self.virtual_token_reserves as u128) - r;
+        let s: u128 = (self.virtual_token_reserves as u128)
+            .checked_sub(r)
+            .ok_or(CurveError::InsufficientLiquidity)?;
 
-        // Convert back to u64 and return the minimum of calculated tokens and real reserves
-        let s_u64 = s as u64;
-        Ok(if s_u64 < self.real_token_reserves {
-            s_u64
-        } else {
-            self.real_token_reserves
-        })
+        // Return the minimum of calculated tokens and real reserves
+        Ok(s.min(self.real_token_reserves as u128) as u64)
+    }
+
+    /// Calculates the amount of SOL required to buy a given token amount
+    ///
+    /// This is the inverse of `get_buy_price` and matches the cost charged by the on-chain
+    /// `buy` instruction, including the protocol fee.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of tokens to buy
+    /// * `fee_basis_points` - Fee in basis points (1/100th of a percent)
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Amount of SOL required including fees
+    /// * `Err(CurveError)` - Error if the curve is complete, has too few tokens or the cost overflows
+    pub fn get_buy_sol_cost(&self, amount: u64, fee_basis_points: u64) -> Result<u64, CurveError> {
+        self.check_tradable()?;
+
+        if amount == 0 {
+            return Ok(0);
+        }
+
+        if amount > self.real_token_reserves || amount >= self.virtual_token_reserves {
+            return Err(CurveError::InsufficientLiquidity);
+        }
+
+        // Calculate the SOL cost of the tokens using u128 to avoid overflow
+        let sol_cost: u128 = (amount as u128) * (self.virtual_sol_reserves as u128)
+            / ((self.virtual_token_reserves - amount) as u128)
+            + 1;
+
+        // Return the total cost including the fee, converting back to u64
+        to_u64(with_fee(sol_cost, fee_basis_points)?)
     }
 
     /// Calculates the amount of SOL received for selling tokens
@@ -123,11 +207,9 @@ impl BondingCurveAccount {
     ///
     /// # Returns
     /// * `Ok(u64)` - Amount of SOL that would be received after fees
-    /// * `Err(&str)` - Error message if curve is complete
-    pub fn get_sell_price(&self, amount: u64, fee_basis_points: u64) -> Result<u64, &'static str> {
-        if self.complete {
-            return Err("Curve is complete");
-        }
+    /// * `Err(CurveError)` - Error if the curve is complete or has empty reserves
+    pub fn get_sell_price(&self, amount: u64, fee_basis_points: u64) -> Result<u64, CurveError> {
+        self.check_tradable()?;
 
         if amount == 0 {
             return Ok(0);
@@ -138,66 +220,356 @@ impl BondingCurveAccount {
             / ((self.virtual_token_reserves as u128) + (amount as u128));
 
         // Calculate the fee amount in the same units
-        let a: u128 = (n * (fee_basis_points as u128)) / 10000;
+        let a: u128 = fee(n, fee_basis_points)?;
 
         // Return the net amount after deducting the fee, converting back to u64
-        Ok((n - a) as u64)
+        to_u64(n.saturating_sub(a))
     }
 
     /// Calculates the current market cap in SOL
+    ///
+    /// # Returns
+    /// Market cap in lamports, 0 if the curve has no virtual tokens or `u64::MAX` if it overflows
     pub fn get_market_cap_sol(&self) -> u64 {
         if self.virtual_token_reserves == 0 {
             return 0;
         }
 
-        ((self.token_total_supply as u128) * (self.virtual_sol_reserves as u128)
-            / (self.virtual_token_reserves as u128)) as u64
+        saturating_u64(
+            (self.token_total_supply as u128) * (self.virtual_sol_reserves as u128)
+                / (self.virtual_token_reserves as u128),
+        )
     }
 
     /// Calculates the final market cap in SOL after all tokens are sold
     ///
+    /// Only depends on the reserves, so it is also defined for complete curves.
+    ///
     /// # Arguments
     /// * `fee_basis_points` - Fee in basis points (1/100th of a percent)
+    ///
+    /// # Returns
+    /// Final market cap in lamports, 0 if no virtual tokens remain after selling the real reserves
+    /// or `u64::MAX` if it overflows
     pub fn get_final_market_cap_sol(&self, fee_basis_points: u64) -> u64 {
-        let total_sell_value: u128 =
-            self.get_buy_out_price(self.real_token_reserves, fee_basis_points) as u128;
-        let total_virtual_value: u128 = (self.virtual_sol_reserves as u128) + total_sell_value;
-        let total_virtual_tokens: u128 =
-            (self.virtual_token_reserves as u128) - (self.real_token_reserves as u128);
+        let total_virtual_tokens: u128 = match self
+            .virtual_token_reserves
+            .checked_sub(self.real_token_reserves)
+        {
+            Some(tokens) if tokens > 0 => tokens as u128,
+            _ => return 0,
+        };
 
-        if total_virtual_tokens == 0 {
-            return 0;
-        }
+        let total_sell_value: u128 = self
+            .buy_out_cost(self.real_token_reserves, fee_basis_points)
+            .unwrap_or(u128::MAX);
+        let total_virtual_value: u128 =
+            (self.virtual_sol_reserves as u128).saturating_add(total_sell_value);
 
-        ((self.token_total_supply as u128) * total_virtual_value / total_virtual_tokens) as u64
+        saturating_u64(
+            (self.token_total_supply as u128).saturating_mul(total_virtual_value)
+                / total_virtual_tokens,
+        )
     }
 
-    /// Calculates the price to buy out all remaining tokens
+    /// Calculates the price to buy out the remaining tokens
     ///
     /// # Arguments
-    /// * `amount` - Amount of tokens to buy
+    /// * `amount` - Amount of tokens to buy, clamped to the real token reserves
     /// * `fee_basis_points` - Fee in basis points (1/100th of a percent)
-    pub fn get_buy_out_price(&self, amount: u64, fee_basis_points: u64) -> u64 {
-        // Get the effective amount of sol tokens
-        let sol_tokens: u128 = if amount < self.real_sol_reserves {
-            self.real_sol_reserves as u128
-        } else {
-            amount as u128
+    ///
+    /// # Returns
+    /// * `Ok(u64)` - Amount of SOL required including fees
+    /// * `Err(CurveError)` - Error if the curve is complete, has too few tokens or the price overflows
+    pub fn get_buy_out_price(&self, amount: u64, fee_basis_points: u64) -> Result<u64, CurveError> {
+        self.check_tradable()?;
+
+        // Tokens beyond the real reserves cannot be bought
+        let tokens: u64 = amount.min(self.real_token_reserves);
+
+        to_u64(self.buy_out_cost(tokens, fee_basis_points)?)
+    }
+
+    /// Calculates the SOL cost of buying tokens out of the virtual reserves, including fees
+    fn buy_out_cost(&self, tokens: u64, fee_basis_points: u64) -> Result<u128, CurveError> {
+        // Calculate the virtual token reserves left after the buy out
+        let remaining_tokens: u64 = match self.virtual_token_reserves.checked_sub(tokens) {
+            Some(remaining) if remaining > 0 => remaining,
+            _ => return Err(CurveError::InsufficientLiquidity),
         };
 
         // Calculate total sell value
-        let total_sell_value: u128 = (sol_tokens * (self.virtual_sol_reserves as u128))
-            / ((self.virtual_token_reserves as u128) - sol_tokens)
-            + 1;
+        let total_sell_value: u128 =
+            (tokens as u128) * (self.virtual_sol_reserves as u128) / (remaining_tokens as u128) + 1;
+
+        // Return total including fee
+        with_fee(total_sell_value, fee_basis_points)
+    }
+
+    /// Applies a buy of an exact token amount to the curve
+    ///
+    /// Updates the virtual and real reserves the same way as the on-chain `buy` instruction and
+    /// marks the curve complete once its real token reserves run out.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of tokens to buy
+    /// * `fee_basis_points` - Fee in basis points (1/100th of a percent)
+    ///
+    /// # Returns
+    /// * `Ok(CurveTrade)` - Executed amounts of the buy
+    /// * `Err(CurveError)` - Error if the curve is complete or cannot fill the buy, leaving it unchanged
+    pub fn apply_buy(
+        &mut self,
+        amount: u64,
+        fee_basis_points: u64,
+    ) -> Result<CurveTrade, CurveError> {
+        let sol_amount = self.get_buy_sol_cost(amount, 0)?;
+        let fee = to_u64(fee(sol_amount as u128, fee_basis_points)?)?;
+
+        let virtual_sol_reserves = self
+            .virtual_sol_reserves
+            .checked_add(sol_amount)
+            .ok_or(CurveError::Overflow)?;
+        let real_sol_reserves = self
+            .real_sol_reserves
+            .checked_add(sol_amount)
+            .ok_or(CurveError::Overflow)?;
+
+        // The token amount is bounded by both token reserves in get_buy_sol_cost
+        self.virtual_token_reserves -= amount;
+        self.real_token_reserves -= amount;
+        self.virtual_sol_reserves = virtual_sol_reserves;
+        self.real_sol_reserves = real_sol_reserves;
+        self.complete = self.real_token_reserves == 0;
+
+        Ok(CurveTrade {
+            token_amount: amount,
+            sol_amount,
+            fee,
+        })
+    }
+
+    /// Applies a sell of a token amount to the curve
+    ///
+    /// Updates the virtual and real reserves the same way as the on-chain `sell` instruction.
+    ///
+    /// # Arguments
+    /// * `amount` - Amount of tokens to sell
+    /// * `fee_basis_points` - Fee in basis points (1/100th of a percent)
+    ///
+    /// # Returns
+    /// * `Ok(CurveTrade)` - Executed amounts of the sell
+    /// * `Err(CurveError)` - Error if the curve is complete or cannot pay out the sell, leaving it unchanged
+    pub fn apply_sell(
+        &mut self,
+        amount: u64,
+        fee_basis_points: u64,
+    ) -> Result<CurveTrade, CurveError> {
+        let sol_amount = self.get_sell_price(amount, 0)?;
+        let fee = to_u64(fee(sol_amount as u128, fee_basis_points)?)?;
+
+        let virtual_token_reserves = self
+            .virtual_token_reserves
+            .checked_add(amount)
+            .ok_or(CurveError::Overflow)?;
+        let real_token_reserves = self
+            .real_token_reserves
+            .checked_add(amount)
+            .ok_or(CurveError::Overflow)?;
+        let real_sol_reserves = self
+            .real_sol_reserves
+            .checked_sub(sol_amount)
+            .ok_or(CurveError::InsufficientLiquidity)?;
+
+        // The SOL amount is below the virtual SOL reserves by construction in get_sell_price
+        self.virtual_token_reserves = virtual_token_reserves;
+        self.real_token_reserves = real_token_reserves;
+        self.virtual_sol_reserves -= sol_amount;
+        self.real_sol_reserves = real_sol_reserves;
+
+        Ok(CurveTrade {
+            token_amount: amount,
+            sol_amount,
+            fee,
+        })
+    }
+
+    /// Syncs the curve reserves from a trade event emitted by the program
+    ///
+    /// Trade events carry the reserves after the trade, so applying the events of a curve in
+    /// order keeps a local copy of it in sync.
+    ///
+    /// # Arguments
+    /// * `event` - Trade event emitted for this curve's mint
+    pub fn apply_trade_event(&mut self, event: &TradeEvent) {
+        self.virtual_token_reserves = event.virtual_token_reserves;
+        self.virtual_sol_reserves = event.virtual_sol_reserves;
+        self.real_token_reserves = event.real_token_reserves;
+        self.real_sol_reserves = event.real_sol_reserves;
+        self.complete = self.real_token_reserves == 0;
+    }
+
+    /// Checks that the curve can be traded against
+    fn check_tradable(&self) -> Result<(), CurveError> {
+        if self.complete {
+            return Err(CurveError::Complete);
+        }
+
+        if self.virtual_token_reserves == 0 || self.virtual_sol_reserves == 0 {
+            return Err(CurveError::ZeroReserves);
+        }
+
+        Ok(())
+    }
+}
+
+/// Bonding curve layout with the creator appended by newer program versions
+#[derive(Debug, Clone, BorshSerialize, BorshDeserialize)]
+pub struct BondingCurveAccountV2 {
+    /// Fields of the original layout
+    pub curve: BondingCurveAccount,
+    /// Creator of the token (stored as bytes)
+    pub creator_bytes: [u8; 32],
+}
+
+impl BondingCurveAccountV2 {
+    /// Size of the layout in bytes
+    pub const LEN: usize = BondingCurveAccount::LEN + 32;
 
-        // Calculate fee
-        let fee: u128 = (total_sell_value * (fee_basis_points as u128)) / 10000;
+    /// Get the creator pubkey
+    pub fn creator(&self) -> Pubkey {
+        Pubkey::new_from_array(self.creator_bytes)
+    }
+}
+
+/// Bonding curve account decoded with the newest layout its data holds
+#[derive(Debug, Clone)]
+pub enum VersionedBondingCurveAccount {
+    /// Original layout
+    V1(BondingCurveAccount),
+    /// Layout with the creator
+    V2(BondingCurveAccountV2),
+}
+
+impl VersionedBondingCurveAccount {
+    /// Deserializes a bonding curve from raw account data, picking the layout from its length
+    ///
+    /// # Arguments
+    /// * `data` - Raw account data
+    ///
+    /// # Returns
+    /// * `Ok(VersionedBondingCurveAccount)` - The deserialized account
+    /// * `Err(ClientError)` - Error if the data is not a bonding curve account or is too short
+    #[allow(clippy::result_large_err)]
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self, ClientError> {
+        if data.len() >= BondingCurveAccountV2::LEN {
+            deserialize_account(data, BondingCurveAccount::DISCRIMINATOR, "BondingCurve")
+                .map(Self::V2)
+        } else {
+            BondingCurveAccount::try_from_account_data(data).map(Self::V1)
+        }
+    }
+
+    /// Gets the fields shared by all layouts
+    pub fn curve(&self) -> &BondingCurveAccount {
+        match self {
+            Self::V1(curve) => curve,
+            Self::V2(account) => &account.curve,
+        }
+    }
+
+    /// Gets the creator, or None for the original layout
+    pub fn creator(&self) -> Option<Pubkey> {
+        match self {
+            Self::V1(_) => None,
+            Self::V2(account) => Some(account.creator()),
+        }
+    }
+}
+
+impl From<VersionedBondingCurveAccount> for BondingCurveAccount {
+    fn from(account: VersionedBondingCurveAccount) -> Self {
+        match account {
+            VersionedBondingCurveAccount::V1(curve) => curve,
+            VersionedBondingCurveAccount::V2(account) => account.curve,
+        }
+    }
+}
+
+/// Conditions selecting bonding curves when enumerating the program's accounts
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BondingCurveFilter {
+    /// Only include curves whose completion flag matches, or both if None
+    pub complete: Option<bool>,
+    /// Only include curves holding at least this many lamports of real SOL reserves
+    pub min_real_sol_reserves: Option<u64>,
+}
 
-        // Return total including fee, converting back to u64
-        (total_sell_value + fee) as u64
+impl BondingCurveFilter {
+    /// Offset of the `complete` flag in the account data
+    const COMPLETE_OFFSET: usize = 8 + 5 * 8;
+
+    /// Builds the `getProgramAccounts` filters evaluated by the RPC node
+    ///
+    /// The filters match the bonding curve discriminator and the completion flag. The RPC node
+    /// can only compare bytes, so the reserves threshold is checked with `matches` instead.
+    ///
+    /// # Returns
+    /// The filters to send with the request
+    pub fn rpc_filters(&self) -> Vec<RpcFilterType> {
+        let mut filters = vec![RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+            0,
+            BondingCurveAccount::DISCRIMINATOR.to_vec(),
+        ))];
+
+        if let Some(complete) = self.complete {
+            filters.push(RpcFilterType::Memcmp(Memcmp::new_raw_bytes(
+                Self::COMPLETE_OFFSET,
+                vec![complete as u8],
+            )));
+        }
+
+        filters
+    }
+
+    /// Checks whether a decoded bonding curve satisfies every condition of the filter
+    ///
+    /// # Arguments
+    /// * `curve` - Decoded bonding curve account
+    pub fn matches(&self, curve: &BondingCurveAccount) -> bool {
+        self.complete
+            .is_none_or(|complete| curve.complete == complete)
+            && self
+                .min_real_sol_reserves
+                .is_none_or(|min| curve.real_sol_reserves >= min)
     }
 }
 
+/// Calculates the fee charged on an amount
+fn fee(amount: u128, fee_basis_points: u64) -> Result<u128, CurveError> {
+    amount
+        .checked_mul(fee_basis_points as u128)
+        .map(|fee| fee / 10000)
+        .ok_or(CurveError::Overflow)
+}
+
+/// Adds the fee charged on an amount to the amount
+fn with_fee(amount: u128, fee_basis_points: u64) -> Result<u128, CurveError> {
+    amount
+        .checked_add(fee(amount, fee_basis_points)?)
+        .ok_or(CurveError::Overflow)
+}
+
+/// Converts a calculated amount back to u64
+fn to_u64(amount: u128) -> Result<u64, CurveError> {
+    u64::try_from(amount).map_err(|_| CurveError::Overflow)
+}
+
+/// Converts a calculated amount back to u64, saturating at `u64::MAX`
+fn saturating_u64(amount: u128) -> u64 {
+    u64::try_from(amount).unwrap_or(u64::MAX)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -244,6 +616,40 @@ mod tests {
         assert!(sell_price > 0);
     }
 
+    #[test]
+    fn test_bonding_curve_filter() {
+        let mut curve = get_bonding_curve();
+        curve.discriminator = u64::from_le_bytes(BondingCurveAccount::DISCRIMINATOR);
+        curve.real_sol_reserves = 5_000;
+        let data = borsh::to_vec(&curve).unwrap();
+
+        let filter = BondingCurveFilter::default();
+        assert_eq!(filter.rpc_filters().len(), 1);
+        assert!(filter.matches(&curve));
+
+        let filter = BondingCurveFilter {
+            complete: Some(false),
+            min_real_sol_reserves: Some(5_000),
+        };
+        let filters = filter.rpc_filters();
+        assert_eq!(filters.len(), 2);
+        assert!(filters.iter().all(|filter| match filter {
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(&data),
+            _ => false,
+        }));
+        assert!(filter.matches(&curve));
+
+        curve.real_sol_reserves = 4_999;
+        assert!(!filter.matches(&curve));
+
+        curve.complete = true;
+        let data = borsh::to_vec(&curve).unwrap();
+        assert!(!filters.iter().all(|filter| match filter {
+            RpcFilterType::Memcmp(memcmp) => memcmp.bytes_match(&data),
+            _ => false,
+        }));
+    }
+
     #[test]
     fn test_bonding_curve_complete() {
         let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
@@ -256,8 +662,129 @@ mod tests {
         bonding_curve.complete = true;
 
         // Test operations fail when complete
-        assert!(bonding_curve.get_buy_price(100).is_err());
-        assert!(bonding_curve.get_sell_price(100, 250).is_err());
+        assert_eq!(bonding_curve.get_buy_price(100), Err(CurveError::Complete));
+        assert_eq!(
+            bonding_curve.get_buy_sol_cost(100, 250),
+            Err(CurveError::Complete)
+        );
+        assert_eq!(
+            bonding_curve.get_sell_price(100, 250),
+            Err(CurveError::Complete)
+        );
+    }
+
+    #[test]
+    fn test_buy_sol_cost() {
+        let bonding_curve: BondingCurveAccount = get_bonding_curve();
+
+        assert_eq!(bonding_curve.get_buy_sol_cost(0, 250).unwrap(), 0);
+
+        // Buying the tokens received for an amount of SOL costs at most that amount
+        let tokens = bonding_curve.get_buy_price(100).unwrap();
+        let cost = bonding_curve.get_buy_sol_cost(tokens, 0).unwrap();
+        assert!(cost > 0);
+        assert!(cost <= 101);
+
+        // Fees are added on top of the cost
+        let cost_with_fee = bonding_curve.get_buy_sol_cost(tokens, 1000).unwrap();
+        assert_eq!(cost_with_fee, cost + cost / 10);
+
+        // Buying more tokens than the real reserves fails
+        assert_eq!(
+            bonding_curve.get_buy_sol_cost(bonding_curve.real_token_reserves + 1, 250),
+            Err(CurveError::InsufficientLiquidity)
+        );
+    }
+
+    #[test]
+    fn test_apply_buy_and_sell() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+        let before = bonding_curve.clone();
+
+        // A buy moves tokens out of the curve and SOL into it
+        let cost = bonding_curve.get_buy_sol_cost(100, 250).unwrap();
+        let buy = bonding_curve.apply_buy(100, 250).unwrap();
+        assert_eq!(buy.token_amount, 100);
+        assert_eq!(buy.sol_amount + buy.fee, cost);
+        assert_eq!(bonding_curve.virtual_token_reserves, 900);
+        assert_eq!(bonding_curve.real_token_reserves, 400);
+        assert_eq!(bonding_curve.virtual_sol_reserves, 1000 + buy.sol_amount);
+        assert_eq!(bonding_curve.real_sol_reserves, 500 + buy.sol_amount);
+        assert!(!bonding_curve.complete);
+
+        // Selling the tokens back returns slightly less SOL due to rounding
+        let proceeds = bonding_curve.get_sell_price(100, 250).unwrap();
+        let sell = bonding_curve.apply_sell(100, 250).unwrap();
+        assert_eq!(sell.sol_amount - sell.fee, proceeds);
+        assert!(sell.sol_amount <= buy.sol_amount);
+        assert_eq!(
+            bonding_curve.virtual_token_reserves,
+            before.virtual_token_reserves
+        );
+        assert_eq!(
+            bonding_curve.real_token_reserves,
+            before.real_token_reserves
+        );
+        assert_eq!(
+            bonding_curve.real_sol_reserves,
+            before.real_sol_reserves + buy.sol_amount - sell.sol_amount
+        );
+    }
+
+    #[test]
+    fn test_apply_buy_completes_curve() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+
+        // Buying more than the remaining tokens fails and leaves the curve unchanged
+        assert_eq!(
+            bonding_curve.apply_buy(501, 250),
+            Err(CurveError::InsufficientLiquidity)
+        );
+        assert_eq!(bonding_curve.real_token_reserves, 500);
+
+        // Buying the remaining tokens completes the curve
+        bonding_curve.apply_buy(500, 250).unwrap();
+        assert_eq!(bonding_curve.real_token_reserves, 0);
+        assert!(bonding_curve.complete);
+        assert_eq!(bonding_curve.apply_buy(1, 250), Err(CurveError::Complete));
+        assert_eq!(bonding_curve.apply_sell(1, 250), Err(CurveError::Complete));
+    }
+
+    #[test]
+    fn test_apply_sell_insufficient_sol() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+        bonding_curve.real_sol_reserves = 0;
+
+        assert_eq!(
+            bonding_curve.apply_sell(100, 250),
+            Err(CurveError::InsufficientLiquidity)
+        );
+        assert_eq!(bonding_curve.virtual_token_reserves, 1000);
+    }
+
+    #[test]
+    fn test_apply_trade_event() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+        let mut simulated = bonding_curve.clone();
+        let buy = simulated.apply_buy(100, 250).unwrap();
+
+        let event = TradeEvent {
+            mint_bytes: [0; 32],
+            sol_amount: buy.sol_amount,
+            token_amount: buy.token_amount,
+            is_buy: true,
+            user_bytes: [0; 32],
+            timestamp: 0,
+            virtual_sol_reserves: simulated.virtual_sol_reserves,
+            virtual_token_reserves: simulated.virtual_token_reserves,
+            real_sol_reserves: simulated.real_sol_reserves,
+            real_token_reserves: simulated.real_token_reserves,
+        };
+        bonding_curve.apply_trade_event(&event);
+        assert_eq!(
+            borsh::to_vec(&bonding_curve).unwrap(),
+            borsh::to_vec(&simulated).unwrap()
+        );
     }
 
     #[test]
@@ -269,19 +796,76 @@ mod tests {
         assert!(market_cap > 0);
 
         let final_market_cap = bonding_curve.get_final_market_cap_sol(250);
-        assert!(final_market_cap > 0);
+        assert!(final_market_cap > market_cap);
+
+        // Complete curves keep their market caps
+        let mut complete = bonding_curve.clone();
+        complete.complete = true;
+        assert_eq!(complete.get_market_cap_sol(), market_cap);
+        assert_eq!(complete.get_final_market_cap_sol(250), final_market_cap);
+
+        // No virtual tokens remain once the real reserves are sold
+        let mut drained = bonding_curve.clone();
+        drained.real_token_reserves = drained.virtual_token_reserves;
+        assert_eq!(drained.get_final_market_cap_sol(250), 0);
     }
 
     #[test]
     fn test_buy_out_price() {
-        let bonding_curve: BondingCurveAccount = get_bonding_curve();
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
 
-        let buy_out_price = bonding_curve.get_buy_out_price(100, 250);
-        assert!(buy_out_price > 0);
+        let buy_out_price = bonding_curve.get_buy_out_price(100, 250).unwrap();
+        assert_eq!(
+            buy_out_price,
+            bonding_curve.get_buy_sol_cost(100, 250).unwrap()
+        );
+
+        // Amounts beyond the real token reserves are clamped to them
+        let full_buy_out = bonding_curve
+            .get_buy_out_price(bonding_curve.real_token_reserves, 250)
+            .unwrap();
+        assert!(full_buy_out > buy_out_price);
+        assert_eq!(
+            bonding_curve.get_buy_out_price(bonding_curve.virtual_token_reserves, 250),
+            Ok(full_buy_out)
+        );
+
+        // A curve whose virtual reserves are all real cannot be bought out
+        let mut drained = bonding_curve.clone();
+        drained.real_token_reserves = drained.virtual_token_reserves;
+        assert_eq!(
+            drained.get_buy_out_price(u64::MAX, 250),
+            Err(CurveError::InsufficientLiquidity)
+        );
+
+        bonding_curve.complete = true;
+        assert_eq!(
+            bonding_curve.get_buy_out_price(100, 250),
+            Err(CurveError::Complete)
+        );
+    }
+
+    #[test]
+    fn test_zero_reserves() {
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+        bonding_curve.virtual_token_reserves = 0;
+
+        assert_eq!(
+            bonding_curve.get_buy_price(100),
+            Err(CurveError::ZeroReserves)
+        );
+        assert_eq!(
+            bonding_curve.get_sell_price(100, 250),
+            Err(CurveError::ZeroReserves)
+        );
+        assert_eq!(bonding_curve.get_market_cap_sol(), 0);
 
-        // Test with amount less than real_sol_reserves
-        let small_buy_out = bonding_curve.get_buy_out_price(400, 250);
-        assert!(small_buy_out > 0);
+        let mut bonding_curve: BondingCurveAccount = get_bonding_curve();
+        bonding_curve.virtual_sol_reserves = 0;
+        assert_eq!(
+            bonding_curve.get_buy_price(100),
+            Err(CurveError::ZeroReserves)
+        );
     }
 
     #[test]
@@ -311,8 +895,22 @@ mod tests {
         let market_cap = bonding_curve.get_market_cap_sol();
         assert!(market_cap > 0);
 
-        let final_market_cap = bonding_curve.get_final_market_cap_sol(250);
-        assert!(final_market_cap > 0);
+        // The final market cap does not fit in u64
+        assert_eq!(bonding_curve.get_final_market_cap_sol(250), u64::MAX);
+    }
+
+    #[test]
+    fn test_overflow_fees() {
+        let bonding_curve = get_large_bonding_curve();
+
+        assert_eq!(
+            bonding_curve.get_buy_sol_cost(u64::MAX / 4, u64::MAX),
+            Err(CurveError::Overflow)
+        );
+        assert_eq!(
+            bonding_curve.get_buy_out_price(u64::MAX / 4, u64::MAX),
+            Err(CurveError::Overflow)
+        );
     }
 
     #[test]
@@ -320,7 +918,67 @@ mod tests {
         let bonding_curve = get_large_bonding_curve();
 
         // Test buy out with large token amount
-        let buy_out_price = bonding_curve.get_buy_out_price(u64::MAX / 4, 250);
+        let buy_out_price = bonding_curve.get_buy_out_price(u64::MAX / 4, 250).unwrap();
         assert!(buy_out_price > 0);
     }
+
+    fn encode_account(curve: &BondingCurveAccount) -> Vec<u8> {
+        let curve = BondingCurveAccount {
+            discriminator: u64::from_le_bytes(BondingCurveAccount::DISCRIMINATOR),
+            ..curve.clone()
+        };
+        borsh::to_vec(&curve).unwrap()
+    }
+
+    #[test]
+    fn test_try_from_account_data() {
+        let data = encode_account(&get_bonding_curve());
+        assert_eq!(data.len(), BondingCurveAccount::LEN);
+
+        let curve = BondingCurveAccount::try_from_account_data(&data).unwrap();
+        assert_eq!(curve.virtual_token_reserves, 1000);
+        assert_eq!(curve.real_sol_reserves, 500);
+        assert!(!curve.complete);
+
+        // Other account types are rejected
+        let mut other = data.clone();
+        other[..8].copy_from_slice(&crate::accounts::GlobalAccount::DISCRIMINATOR);
+        assert!(matches!(
+            BondingCurveAccount::try_from_account_data(&other),
+            Err(ClientError::InvalidAccountDiscriminator("BondingCurve"))
+        ));
+        assert!(matches!(
+            BondingCurveAccount::try_from_account_data(&data[..4]),
+            Err(ClientError::InvalidAccountDiscriminator(_))
+        ));
+
+        // Truncated accounts fail to deserialize
+        assert!(matches!(
+            BondingCurveAccount::try_from_account_data(&data[..20]),
+            Err(ClientError::BorshError(_))
+        ));
+    }
+
+    #[test]
+    fn test_versioned_account() {
+        let data = encode_account(&get_bonding_curve());
+        let account = VersionedBondingCurveAccount::try_from_account_data(&data).unwrap();
+        assert!(matches!(account, VersionedBondingCurveAccount::V1(_)));
+        assert_eq!(account.creator(), None);
+
+        // Newer layouts append a creator and padding
+        let creator = Pubkey::new_unique();
+        let mut extended = data.clone();
+        extended.extend_from_slice(&creator.to_bytes());
+        extended.resize(150, 0);
+
+        let account = VersionedBondingCurveAccount::try_from_account_data(&extended).unwrap();
+        assert_eq!(account.creator(), Some(creator));
+        assert_eq!(account.curve().virtual_sol_reserves, 1000);
+
+        // The original layout still decodes from the extended data
+        let curve = BondingCurveAccount::try_from_account_data(&extended).unwrap();
+        assert_eq!(curve.real_token_reserves, 500);
+        assert_eq!(BondingCurveAccount::from(account).token_total_supply, 1000);
+    }
 }
@@ -1,3 +1,5 @@
+use super::{deserialize_account, BondingCurveAccount};
+use crate::error::{ClientError, CurveError};
 use anchor_client::solana_sdk::pubkey::Pubkey;
 use borsh::{BorshDeserialize, BorshSerialize};
 
@@ -25,6 +27,26 @@ pub struct GlobalAccount {
 }
 
 impl GlobalAccount {
+    /// Anchor discriminator of the account, the first 8 bytes of `sha256("account:Global")`
+    pub const DISCRIMINATOR: [u8; 8] = [167, 232, 232, 177, 200, 108, 114, 127];
+
+    /// Deserializes a global account from raw account data
+    ///
+    /// Validates the Anchor discriminator and ignores fields appended by newer program versions.
+    /// Unlike bonding curves there is no versioned layout, as trading and pricing only use the
+    /// original fields of the global account.
+    ///
+    /// # Arguments
+    /// * `data` - Raw account data
+    ///
+    /// # Returns
+    /// * `Ok(GlobalAccount)` - The deserialized account
+    /// * `Err(ClientError)` - Error if the data is not a global account or is too short
+    #[allow(clippy::result_large_err)]
+    pub fn try_from_account_data(data: &[u8]) -> Result<Self, ClientError> {
+        deserialize_account(data, Self::DISCRIMINATOR, "Global")
+    }
+
     /// Creates a new global account instance
     ///
     /// # Arguments
@@ -72,28 +94,77 @@ impl GlobalAccount {
         Pubkey::new_from_array(self.fee_recipient_bytes)
     }
 
+    /// Gets the bonding curve a newly created token starts with
+    ///
+    /// # Returns
+    /// Bonding curve initialized with the global initial reserves and token supply
+    pub fn initial_bonding_curve(&self) -> BondingCurveAccount {
+        BondingCurveAccount::new(
+            0,
+            self.initial_virtual_token_reserves,
+            self.initial_virtual_sol_reserves,
+            self.initial_real_token_reserves,
+            0,
+            self.token_total_supply,
+            false,
+        )
+    }
+
     /// Calculates the initial amount of tokens received for a given SOL amount
     ///
     /// # Arguments
     /// * `amount` - Amount of SOL to spend
     ///
     /// # Returns
-    /// Amount of tokens that would be received
-    pub fn get_initial_buy_price(&self, amount: u64) -> u64 {
-        if amount == 0 {
-            return 0;
-        }
+    /// * `Ok(u64)` - Amount of tokens that would be received
+    /// * `Err(CurveError)` - Error if the initial reserves are empty
+    pub fn get_initial_buy_price(&self, amount: u64) -> Result<u64, CurveError> {
+        self.initial_bonding_curve().get_buy_price(amount)
+    }
+}
 
-        let n: u128 = (self.initial_virtual_sol_reserves as u128)
-            * (self.initial_virtual_token_reserves as u128);
-        let i: u128 = (self.initial_virtual_sol_reserves as u128) + (amount as u128);
-        let r: u128 = n / i + 1;
-        let s: u128 = (self.initial_virtual_token_reserves as u128) - r;
+#[cfg(test)]
+mod tests {
+    use super::*;
 
-        if s < (self.initial_real_token_reserves as u128) {
-            s as u64
-        } else {
-            self.initial_real_token_reserves
-        }
+    fn encode_account() -> Vec<u8> {
+        let global = GlobalAccount::new(
+            u64::from_le_bytes(GlobalAccount::DISCRIMINATOR),
+            true,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1000,
+            1000,
+            500,
+            1000,
+            100,
+        );
+        borsh::to_vec(&global).unwrap()
+    }
+
+    #[test]
+    fn test_try_from_account_data() {
+        let data = encode_account();
+
+        let global = GlobalAccount::try_from_account_data(&data).unwrap();
+        assert!(global.initialized);
+        assert_eq!(global.initial_real_token_reserves, 500);
+        assert_eq!(global.fee_basis_points, 100);
+
+        // Other account types are rejected
+        let mut other = data.clone();
+        other[..8].copy_from_slice(&BondingCurveAccount::DISCRIMINATOR);
+        assert!(matches!(
+            GlobalAccount::try_from_account_data(&other),
+            Err(ClientError::InvalidAccountDiscriminator("Global"))
+        ));
+
+        // Fields appended by newer program versions are ignored
+        let mut extended = data.clone();
+        extended.extend_from_slice(&Pubkey::new_unique().to_bytes());
+        extended.resize(data.len() + 128, 0);
+        let global = GlobalAccount::try_from_account_data(&extended).unwrap();
+        assert_eq!(global.token_total_supply, 1000);
+        assert_eq!(global.fee_basis_points, 100);
     }
 }
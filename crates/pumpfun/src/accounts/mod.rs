@@ -6,9 +6,38 @@
 //!
 //! - `BondingCurve`: Represents a bonding curve account.
 //! - `Global`: Represents the global configuration account.
+//!
+//! Accounts are decoded with `try_from_account_data`, which validates the 8-byte Anchor
+//! discriminator and ignores bytes appended by newer program versions. Bonding curves can also be
+//! decoded as a `VersionedBondingCurveAccount` to read those extra fields when present.
 
 mod bonding_curve;
 mod global;
 
 pub use bonding_curve::*;
 pub use global::*;
+
+use crate::error::ClientError;
+use borsh::BorshDeserialize;
+
+/// Deserializes an Anchor account after validating its discriminator
+///
+/// The discriminator is kept as the leading field of the deserialized struct. Trailing bytes
+/// beyond the struct's layout are ignored.
+///
+/// # Arguments
+/// * `data` - Raw account data
+/// * `discriminator` - Expected Anchor discriminator of the account type
+/// * `name` - Name of the account type reported on mismatch
+#[allow(clippy::result_large_err)]
+fn deserialize_account<T: BorshDeserialize>(
+    data: &[u8],
+    discriminator: [u8; 8],
+    name: &'static str,
+) -> Result<T, ClientError> {
+    if !data.starts_with(&discriminator) {
+        return Err(ClientError::InvalidAccountDiscriminator(name));
+    }
+
+    T::deserialize(&mut &data[..]).map_err(ClientError::BorshError)
+}
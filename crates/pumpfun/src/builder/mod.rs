@@ -0,0 +1,524 @@
+//! Builder for configuring `PumpFun` clients and `PumpFunReader`s.
+//!
+//! This module provides `PumpFunBuilder`, created with `PumpFun::builder` or
+//! `PumpFunReader::builder`, which configures the endpoints, commitment, HTTP settings and trading
+//! defaults of a client. HTTP and websocket
+//! endpoints are set separately, so clients can target private RPC gateways that require
+//! authentication headers, or reuse an `RpcClient` created by the caller.
+//!
+//! Headers are only sent by the HTTP RPC client. Websocket subscriptions connect without them;
+//! gateways usually authenticate websockets with a token in the URL instead.
+//!
+//! When only the HTTP endpoint is overridden, the websocket endpoint is derived from it following
+//! the Solana convention: `http` becomes `ws`, `https` becomes `wss` and an explicit port is
+//! incremented by one.
+
+use crate::{
+    config, error, fee, ratelimit, reader::PumpFunReader, subscription, AutoComputeUnitLimit,
+    PumpFun,
+};
+use anchor_client::{
+    solana_client::{nonblocking::rpc_client::RpcClient, rpc_client::RpcClientConfig},
+    solana_sdk::{commitment_config::CommitmentConfig, signer::Signer},
+    Cluster,
+};
+use reqwest::header::{HeaderName, HeaderValue};
+use solana_rpc_client::http_sender::HttpSender;
+use std::{ops::Deref, time::Duration};
+
+/// Default timeout of HTTP RPC requests
+pub const DEFAULT_TIMEOUT: Duration = Duration::from_secs(30);
+
+/// Default maximum slippage of trades in basis points (1 bp = 0.01%)
+pub const DEFAULT_SLIPPAGE_BASIS_POINTS: u64 = 500;
+
+/// Builder for a `PumpFun` client, or a `PumpFunReader` when the payer is `()`
+pub struct PumpFunBuilder<C> {
+    payer: C,
+    cluster: Cluster,
+    http_url: Option<String>,
+    ws_url: Option<String>,
+    commitment: CommitmentConfig,
+    timeout: Duration,
+    confirm_transaction_timeout: Option<Duration>,
+    headers: Vec<(String, String)>,
+    rpc: Option<RpcClient>,
+    program_config: config::ProgramConfig,
+    default_slippage_basis_points: u64,
+    default_priority_fee: Option<fee::FeeStrategy>,
+    auto_compute_unit_limit: Option<AutoComputeUnitLimit>,
+    preflight_checks: bool,
+    rate_limit: Option<ratelimit::RateLimit>,
+}
+
+impl<C> PumpFunBuilder<C> {
+    /// Creates a builder for a mainnet client paying with the given signer
+    ///
+    /// # Arguments
+    /// * `payer` - Signer used to sign and pay for transactions, or `()` for a reader
+    pub fn new(payer: C) -> Self {
+        Self {
+            payer,
+            cluster: Cluster::Mainnet,
+            http_url: None,
+            ws_url: None,
+            commitment: CommitmentConfig::default(),
+            timeout: DEFAULT_TIMEOUT,
+            confirm_transaction_timeout: None,
+            headers: Vec::new(),
+            rpc: None,
+            program_config: config::ProgramConfig::default(),
+            default_slippage_basis_points: DEFAULT_SLIPPAGE_BASIS_POINTS,
+            default_priority_fee: None,
+            auto_compute_unit_limit: None,
+            preflight_checks: true,
+            rate_limit: None,
+        }
+    }
+
+    /// Sets the cluster whose endpoints are used unless overridden by `http_url` or `ws_url`
+    pub fn cluster(mut self, cluster: Cluster) -> Self {
+        self.cluster = cluster;
+        self
+    }
+
+    /// Sets the HTTP endpoint of RPC requests and transactions, ignored when `rpc_client` is set
+    pub fn http_url(mut self, url: impl Into<String>) -> Self {
+        self.http_url = Some(url.into());
+        self
+    }
+
+    /// Sets the websocket endpoint of subscriptions
+    pub fn ws_url(mut self, url: impl Into<String>) -> Self {
+        self.ws_url = Some(url.into());
+        self
+    }
+
+    /// Sets the commitment of reads, transaction confirmations and subscriptions
+    pub fn commitment(mut self, commitment: CommitmentConfig) -> Self {
+        self.commitment = commitment;
+        self
+    }
+
+    /// Sets the timeout of each HTTP RPC request, 30 seconds by default
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets how long to wait for a sent transaction to be seen by the cluster before giving up
+    pub fn confirm_transaction_timeout(mut self, timeout: Duration) -> Self {
+        self.confirm_transaction_timeout = Some(timeout);
+        self
+    }
+
+    /// Adds a header sent with every HTTP RPC request, e.g. for authentication
+    ///
+    /// Headers are not sent by websocket subscriptions.
+    pub fn header(mut self, name: impl Into<String>, value: impl Into<String>) -> Self {
+        self.headers.push((name.into(), value.into()));
+        self
+    }
+
+    /// Uses an RPC client created by the caller for RPC requests and transactions
+    ///
+    /// The commitment, timeouts and headers configured on the builder do not apply to the given
+    /// client. The cluster keeps the configured HTTP endpoint, so set `http_url` and `ws_url` to
+    /// the endpoints the client targets.
+    pub fn rpc_client(mut self, rpc: RpcClient) -> Self {
+        self.rpc = Some(rpc);
+        self
+    }
+
+    /// Targets a program deployed under a custom configuration, e.g. on localnet or a fork
+    pub fn program_config(mut self, config: config::ProgramConfig) -> Self {
+        self.program_config = config;
+        self
+    }
+
+    /// Sets the slippage used by trades that do not specify one, in basis points (1 bp = 0.01%)
+    pub fn default_slippage_basis_points(mut self, slippage_basis_points: u64) -> Self {
+        self.default_slippage_basis_points = slippage_basis_points;
+        self
+    }
+
+    /// Sets the priority fee strategy used by transactions that do not specify one
+    pub fn default_priority_fee(mut self, priority_fee: fee::FeeStrategy) -> Self {
+        self.default_priority_fee = Some(priority_fee);
+        self
+    }
+
+    /// Enables sizing the compute unit limit automatically from a simulation
+    pub fn auto_compute_unit_limit(mut self, config: AutoComputeUnitLimit) -> Self {
+        self.auto_compute_unit_limit = Some(config);
+        self
+    }
+
+    /// Sets whether trades check the payer's balances before sending, enabled by default
+    pub fn preflight_checks(mut self, enabled: bool) -> Self {
+        self.preflight_checks = enabled;
+        self
+    }
+
+    /// Limits the rate of RPC requests and metadata uploads, see `PumpFun::with_rate_limit`
+    pub fn rate_limit(
+        mut self,
+        limiter: ratelimit::RateLimiter,
+        retry: ratelimit::RetryPolicy,
+    ) -> Self {
+        self.rate_limit = Some(ratelimit::RateLimit { limiter, retry });
+        self
+    }
+
+    /// Builds a read-only client, ignoring the payer and trading-only settings
+    ///
+    /// # Returns
+    /// The configured reader, or a ClientError if a header is invalid or the HTTP client cannot be created
+    #[allow(clippy::result_large_err)]
+    pub fn build_reader(mut self) -> Result<PumpFunReader, error::ClientError> {
+        let rpc = match self.rpc.take() {
+            Some(rpc) => rpc,
+            None => self.http_rpc_client()?,
+        };
+        Ok(self.build_reader_with_rpc(rpc))
+    }
+
+    /// Builds a read-only client around an RPC client
+    ///
+    /// # Arguments
+    /// * `rpc` - RPC client used instead of `rpc_client` or one created from the HTTP settings
+    pub(crate) fn build_reader_with_rpc(self, rpc: RpcClient) -> PumpFunReader {
+        // The endpoints come from the configuration, the RPC client may target any URL
+        let http_url = self
+            .http_url
+            .unwrap_or_else(|| self.cluster.url().to_string());
+        let ws_url = self
+            .ws_url
+            .or_else(|| {
+                (http_url != self.cluster.url())
+                    .then(|| websocket_url(&http_url))
+                    .flatten()
+            })
+            .unwrap_or_else(|| self.cluster.ws_url().to_string());
+
+        // Keep named clusters when their endpoints are used as-is
+        let cluster = if http_url == self.cluster.url() && ws_url == self.cluster.ws_url() {
+            self.cluster
+        } else {
+            Cluster::Custom(http_url, ws_url.clone())
+        };
+
+        let reader = PumpFunReader {
+            rpc,
+            pubsub: subscription::SharedPubsubClient::new(&ws_url),
+            cluster,
+            config: self.program_config,
+            rate_limit: None,
+            default_slippage_basis_points: self.default_slippage_basis_points,
+        };
+
+        match self.rate_limit {
+            Some(rate_limit) => reader.with_rate_limit(rate_limit.limiter, rate_limit.retry),
+            None => reader,
+        }
+    }
+
+    /// Creates an RPC client for the HTTP endpoint with the configured timeouts and headers
+    #[allow(clippy::result_large_err)]
+    fn http_rpc_client(&self) -> Result<RpcClient, error::ClientError> {
+        let http_url = self
+            .http_url
+            .clone()
+            .unwrap_or_else(|| self.cluster.url().to_string());
+
+        let mut headers = HttpSender::default_headers();
+        for (name, value) in &self.headers {
+            let name = HeaderName::from_bytes(name.as_bytes())
+                .map_err(|_| error::ClientError::InvalidInput("Invalid header name"))?;
+            let value = HeaderValue::from_str(value)
+                .map_err(|_| error::ClientError::InvalidInput("Invalid header value"))?;
+            headers.insert(name, value);
+        }
+
+        let client = reqwest::Client::builder()
+            .default_headers(headers)
+            .timeout(self.timeout)
+            .build()
+            .map_err(|_| error::ClientError::InvalidInput("Invalid HTTP client options"))?;
+
+        Ok(RpcClient::new_sender(
+            HttpSender::new_with_client(http_url, client),
+            RpcClientConfig {
+                commitment_config: self.commitment,
+                confirm_transaction_initial_timeout: self.confirm_transaction_timeout,
+            },
+        ))
+    }
+}
+
+impl<C: Clone + Deref<Target = impl Signer>> PumpFunBuilder<C> {
+    /// Builds the client
+    ///
+    /// # Returns
+    /// The configured client, or a ClientError if a header is invalid or the HTTP client cannot be created
+    #[allow(clippy::result_large_err)]
+    pub fn build(mut self) -> Result<PumpFun<C>, error::ClientError> {
+        let rpc = match self.rpc.take() {
+            Some(rpc) => rpc,
+            None => self.http_rpc_client()?,
+        };
+        Ok(self.build_with_rpc(rpc))
+    }
+
+    /// Builds the client around an RPC client
+    ///
+    /// # Arguments
+    /// * `rpc` - RPC client used instead of `rpc_client` or one created from the HTTP settings
+    pub(crate) fn build_with_rpc(self, rpc: RpcClient) -> PumpFun<C> {
+        let payer = self.payer.clone();
+        let auto_compute_unit_limit = self.auto_compute_unit_limit;
+        let preflight_checks = self.preflight_checks;
+        let default_priority_fee = self.default_priority_fee;
+
+        PumpFun {
+            reader: self.build_reader_with_rpc(rpc),
+            payer,
+            auto_compute_unit_limit,
+            preflight_checks,
+            default_priority_fee,
+        }
+    }
+}
+
+/// Derives the websocket endpoint matching an HTTP endpoint
+///
+/// # Arguments
+/// * `http_url` - HTTP endpoint of the RPC node
+///
+/// # Returns
+/// The websocket endpoint, the URL itself if it is not an HTTP URL, or None if it cannot be parsed
+fn websocket_url(http_url: &str) -> Option<String> {
+    let mut url = reqwest::Url::parse(http_url).ok()?;
+    let scheme = match url.scheme() {
+        "http" => "ws",
+        "https" => "wss",
+        _ => return Some(http_url.to_string()),
+    };
+    url.set_scheme(scheme).ok()?;
+    if let Some(port) = url.port() {
+        url.set_port(Some(port.checked_add(1)?)).ok()?;
+    }
+    Some(url.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::solana_sdk::signature::Keypair;
+    use serde_json::{json, Value};
+    use std::sync::{Arc, Mutex};
+    use tokio::{
+        io::{AsyncReadExt, AsyncWriteExt},
+        net::TcpListener,
+    };
+
+    #[test]
+    fn test_builder_defaults() {
+        let client = PumpFun::builder(Arc::new(Keypair::new())).build().unwrap();
+
+        assert_eq!(client.rpc.url(), Cluster::Mainnet.url());
+        assert_eq!(client.cluster, Cluster::Mainnet);
+        assert_eq!(client.rpc.commitment(), CommitmentConfig::default());
+        assert_eq!(
+            client.default_slippage_basis_points,
+            DEFAULT_SLIPPAGE_BASIS_POINTS
+        );
+        assert!(client.default_priority_fee.is_none());
+        assert!(client.preflight_checks);
+        assert!(client.rate_limit.is_none());
+    }
+
+    #[test]
+    fn test_builder_custom_endpoints() {
+        let priority_fee = fee::FeeStrategy::Percentile {
+            limit: None,
+            percentile: 75,
+        };
+        let client = PumpFun::builder(Arc::new(Keypair::new()))
+            .http_url("https://rpc.example.com")
+            .ws_url("wss://ws.example.com")
+            .commitment(CommitmentConfig::confirmed())
+            .timeout(Duration::from_secs(5))
+            .header("Authorization", "Bearer token")
+            .default_slippage_basis_points(100)
+            .default_priority_fee(priority_fee)
+            .preflight_checks(false)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.rpc.url(), "https://rpc.example.com");
+        assert_eq!(client.cluster.url(), "https://rpc.example.com");
+        assert_eq!(client.cluster.ws_url(), "wss://ws.example.com");
+        assert_eq!(client.rpc.commitment(), CommitmentConfig::confirmed());
+        assert_eq!(client.default_slippage_basis_points, 100);
+        assert_eq!(client.default_priority_fee, Some(priority_fee));
+        assert!(!client.preflight_checks);
+    }
+
+    #[test]
+    fn test_builder_reader() {
+        let reader = PumpFunReader::builder()
+            .cluster(Cluster::Devnet)
+            .commitment(CommitmentConfig::confirmed())
+            .default_slippage_basis_points(100)
+            .build_reader()
+            .unwrap();
+
+        assert_eq!(reader.rpc.url(), Cluster::Devnet.url());
+        assert_eq!(reader.cluster.ws_url(), Cluster::Devnet.ws_url());
+        assert_eq!(reader.rpc.commitment(), CommitmentConfig::confirmed());
+        assert_eq!(reader.default_slippage_basis_points, 100);
+    }
+
+    #[test]
+    fn test_builder_invalid_header() {
+        let result = PumpFun::builder(Arc::new(Keypair::new()))
+            .header("Invalid Header", "value")
+            .build();
+        assert!(matches!(
+            result,
+            Err(error::ClientError::InvalidInput("Invalid header name"))
+        ));
+    }
+
+    #[test]
+    fn test_builder_rpc_client() {
+        let rpc = RpcClient::new_with_commitment(
+            "http://localhost:8899".to_string(),
+            CommitmentConfig::processed(),
+        );
+        let client = PumpFun::builder(Arc::new(Keypair::new()))
+            .cluster(Cluster::Localnet)
+            .rpc_client(rpc)
+            .build()
+            .unwrap();
+
+        assert_eq!(client.rpc.url(), "http://localhost:8899");
+        assert_eq!(client.rpc.commitment(), CommitmentConfig::processed());
+
+        // The cluster follows the configured endpoints, not the URL of the caller's client
+        assert_eq!(client.cluster, Cluster::Localnet);
+
+        let rpc = RpcClient::new(Cluster::Devnet.ws_url().to_string());
+        let reader = PumpFunReader::builder()
+            .cluster(Cluster::Devnet)
+            .rpc_client(rpc)
+            .build_reader()
+            .unwrap();
+        assert_eq!(reader.cluster, Cluster::Devnet);
+    }
+
+    #[test]
+    fn test_builder_derives_websocket_url() {
+        let reader = PumpFunReader::builder()
+            .http_url("https://rpc.example.com")
+            .build_reader()
+            .unwrap();
+        assert_eq!(reader.cluster.url(), "https://rpc.example.com");
+        assert_eq!(reader.cluster.ws_url(), "wss://rpc.example.com/");
+
+        // Named clusters are kept when their endpoints are not overridden
+        let client = PumpFun::builder(Arc::new(Keypair::new()))
+            .cluster(Cluster::Devnet)
+            .build()
+            .unwrap();
+        assert_eq!(client.cluster, Cluster::Devnet);
+    }
+
+    /// Serves `getVersion` over HTTP, closing every connection after one response.
+    ///
+    /// Returns the HTTP URL and the raw head of every request received.
+    async fn mock_http_server() -> (String, Arc<Mutex<Vec<String>>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("http://{}", listener.local_addr().unwrap());
+        let heads = Arc::new(Mutex::new(Vec::new()));
+
+        let received = heads.clone();
+        tokio::spawn(async move {
+            loop {
+                let (mut stream, _) = listener.accept().await.unwrap();
+                let mut request = Vec::new();
+                let mut buffer = [0u8; 4096];
+
+                // Read the head and the body announced by its content length
+                let (head, body) = loop {
+                    let read = stream.read(&mut buffer).await.unwrap();
+                    request.extend_from_slice(&buffer[..read]);
+                    let text = String::from_utf8_lossy(&request).to_string();
+                    if let Some((head, body)) = text.split_once("\r\n\r\n") {
+                        let length = head
+                            .lines()
+                            .find_map(|line| {
+                                let (name, value) = line.split_once(':')?;
+                                name.eq_ignore_ascii_case("content-length")
+                                    .then(|| value.trim().parse::<usize>().ok())
+                                    .flatten()
+                            })
+                            .unwrap_or(0);
+                        if body.len() >= length {
+                            break (head.to_lowercase(), body.to_string());
+                        }
+                    }
+                };
+
+                let request: Value = serde_json::from_str(&body).unwrap();
+                let response = json!({
+                    "jsonrpc": "2.0",
+                    "result": { "solana-core": "1.16.25", "feature-set": 0 },
+                    "id": request["id"]
+                })
+                .to_string();
+                received.lock().unwrap().push(head);
+
+                let reply = format!(
+                    "HTTP/1.1 200 OK\r\ncontent-type: application/json\r\ncontent-length: {}\r\nconnection: close\r\n\r\n{}",
+                    response.len(),
+                    response
+                );
+                stream.write_all(reply.as_bytes()).await.unwrap();
+            }
+        });
+
+        (url, heads)
+    }
+
+    #[tokio::test]
+    async fn test_builder_headers() {
+        let (url, heads) = mock_http_server().await;
+
+        let client = PumpFun::builder(Arc::new(Keypair::new()))
+            .http_url(url.clone())
+            .header("Authorization", "Bearer token")
+            .build()
+            .unwrap();
+        client.rpc.get_version().await.unwrap();
+
+        // Rate limiting wraps the HTTP client instead of replacing it
+        let reader = PumpFunReader::builder()
+            .http_url(url)
+            .header("Authorization", "Bearer token")
+            .rate_limit(
+                ratelimit::RateLimiter::new(100, 10),
+                ratelimit::RetryPolicy::default(),
+            )
+            .build_reader()
+            .unwrap();
+        reader.rpc.get_version().await.unwrap();
+
+        let heads = heads.lock().unwrap();
+        assert_eq!(heads.len(), 2);
+        assert!(heads
+            .iter()
+            .all(|head| head.contains("authorization: bearer token")));
+    }
+}
@@ -0,0 +1,157 @@
+//! Program configuration for mainnet, forks and local deployments.
+//!
+//! This module provides the `ProgramConfig` carried by `PumpFun`, which supplies the program ID,
+//! event authority and metadata program used to derive PDAs and build instructions. The default
+//! configuration targets the Pump.fun program deployed on mainnet.
+
+use crate::constants;
+use anchor_client::solana_sdk::pubkey::Pubkey;
+use serde::{Deserialize, Serialize};
+
+/// Addresses of the Pump.fun program and the programs it depends on
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ProgramConfig {
+    /// Pump.fun program ID
+    pub program_id: Pubkey,
+    /// Authority signing the program's self-CPI events
+    pub event_authority: Pubkey,
+    /// MPL Token Metadata program ID
+    pub metadata_program: Pubkey,
+}
+
+impl ProgramConfig {
+    /// Creates a configuration for a program deployed under the given address
+    ///
+    /// The event authority is derived from the program ID and the metadata program is the
+    /// MPL Token Metadata program.
+    ///
+    /// # Arguments
+    /// * `program_id` - Address the program is deployed under
+    pub fn new(program_id: Pubkey) -> Self {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::EVENT_AUTHORITY_SEED];
+        Self {
+            program_id,
+            event_authority: Pubkey::find_program_address(seeds, &program_id).0,
+            metadata_program: constants::accounts::MPL_TOKEN_METADATA,
+        }
+    }
+
+    /// Gets the Program Derived Address (PDA) for the global state account
+    ///
+    /// # Returns
+    /// The PDA public key derived from the GLOBAL_SEED
+    pub fn global_pda(&self) -> Pubkey {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::GLOBAL_SEED];
+        Pubkey::find_program_address(seeds, &self.program_id).0
+    }
+
+    /// Gets the Program Derived Address (PDA) for the mint authority
+    ///
+    /// # Returns
+    /// The PDA public key derived from the MINT_AUTHORITY_SEED
+    pub fn mint_authority_pda(&self) -> Pubkey {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::MINT_AUTHORITY_SEED];
+        Pubkey::find_program_address(seeds, &self.program_id).0
+    }
+
+    /// Gets the Program Derived Address (PDA) for the account tracking the last withdraw
+    ///
+    /// # Returns
+    /// The PDA public key derived from the LAST_WITHDRAW_SEED
+    pub fn last_withdraw_pda(&self) -> Pubkey {
+        let seeds: &[&[u8]; 1] = &[constants::seeds::LAST_WITHDRAW_SEED];
+        Pubkey::find_program_address(seeds, &self.program_id).0
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's bonding curve account
+    ///
+    /// # Arguments
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    /// Some(PDA) if derivation succeeds, or None if it fails
+    pub fn bonding_curve_pda(&self, mint: &Pubkey) -> Option<Pubkey> {
+        let seeds: &[&[u8]; 2] = &[constants::seeds::BONDING_CURVE_SEED, mint.as_ref()];
+        Pubkey::try_find_program_address(seeds, &self.program_id).map(|pda| pda.0)
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's metadata account
+    ///
+    /// # Arguments
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    /// The PDA public key for the token's metadata account
+    pub fn metadata_pda(&self, mint: &Pubkey) -> Pubkey {
+        let seeds: &[&[u8]; 3] = &[
+            constants::seeds::METADATA_SEED,
+            self.metadata_program.as_ref(),
+            mint.as_ref(),
+        ];
+        Pubkey::find_program_address(seeds, &self.metadata_program).0
+    }
+}
+
+impl Default for ProgramConfig {
+    /// Configuration for the Pump.fun program deployed on mainnet
+    fn default() -> Self {
+        Self {
+            program_id: constants::accounts::PUMPFUN,
+            event_authority: constants::accounts::EVENT_AUTHORITY,
+            metadata_program: constants::accounts::MPL_TOKEN_METADATA,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_default_config() {
+        let config = ProgramConfig::default();
+        assert_eq!(config.program_id, crate::cpi::ID);
+
+        // Deriving the mainnet configuration yields the hard-coded event authority
+        assert_eq!(ProgramConfig::new(crate::cpi::ID), config);
+    }
+
+    #[test]
+    fn test_matches_generated_pdas() {
+        let mint = Pubkey::new_unique();
+        let config = ProgramConfig::new(Pubkey::new_unique());
+        let program_id = &config.program_id;
+
+        assert_eq!(
+            config.global_pda(),
+            crate::cpi::pda::find_global(program_id).0
+        );
+        assert_eq!(
+            config.mint_authority_pda(),
+            crate::cpi::pda::find_mint_authority(program_id).0
+        );
+        assert_eq!(
+            config.bonding_curve_pda(&mint),
+            Some(crate::cpi::pda::find_bonding_curve(&mint, program_id).0)
+        );
+    }
+
+    #[test]
+    fn test_custom_program_id() {
+        let mint = Pubkey::new_unique();
+        let default = ProgramConfig::default();
+        let config = ProgramConfig::new(Pubkey::new_unique());
+
+        assert_ne!(config.event_authority, default.event_authority);
+        assert_ne!(config.global_pda(), default.global_pda());
+        assert_ne!(config.mint_authority_pda(), default.mint_authority_pda());
+        assert_ne!(config.last_withdraw_pda(), default.last_withdraw_pda());
+        assert_ne!(
+            config.bonding_curve_pda(&mint),
+            default.bonding_curve_pda(&mint)
+        );
+
+        // Metadata accounts belong to the metadata program, not the Pump.fun program
+        assert_eq!(config.metadata_pda(&mint), default.metadata_pda(&mint));
+    }
+}
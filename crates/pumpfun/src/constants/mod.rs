@@ -12,17 +12,29 @@
 
 /// Constants used as seeds for deriving PDAs (Program Derived Addresses)
 pub mod seeds {
+    use crate::cpi;
+
     /// Seed for the global state PDA
-    pub const GLOBAL_SEED: &[u8] = b"global";
+    pub const GLOBAL_SEED: &[u8] = cpi::seeds::GLOBAL_SEED;
 
     /// Seed for the mint authority PDA
-    pub const MINT_AUTHORITY_SEED: &[u8] = b"mint-authority";
+    pub const MINT_AUTHORITY_SEED: &[u8] = cpi::seeds::MINT_AUTHORITY_SEED;
 
     /// Seed for bonding curve PDAs
-    pub const BONDING_CURVE_SEED: &[u8] = b"bonding-curve";
+    pub const BONDING_CURVE_SEED: &[u8] = cpi::seeds::BONDING_CURVE_SEED;
+
+    // The IDL does not declare the seeds of the following PDAs: metadata accounts are derived by
+    // the MPL Token Metadata program, the last withdraw PDA is not an account of any instruction
+    // and the event authority is derived by Anchor's `emit_cpi`
 
     /// Seed for metadata PDAs
     pub const METADATA_SEED: &[u8] = b"metadata";
+
+    /// Seed for the last withdraw PDA
+    pub const LAST_WITHDRAW_SEED: &[u8] = b"last-withdraw";
+
+    /// Seed for the event authority PDA
+    pub const EVENT_AUTHORITY_SEED: &[u8] = b"__event_authority";
 }
 
 /// Constants related to program accounts and authorities
@@ -0,0 +1,951 @@
+//! Decoder for Pump.fun instructions in arbitrary transactions.
+//!
+//! This module turns the Pump.fun instructions of a transaction into typed instructions with their
+//! arguments and named accounts. Instruction data is matched against the discriminators of the
+//! `pumpfun-cpi` instructions, and accounts are read in the order used by the `instruction` module
+//! and the program IDL.
+//!
+//! Confirmed transactions also carry the inner instructions of their CPIs, so Pump.fun instructions
+//! invoked by other programs, e.g. trading routers, are decoded as well.
+//!
+//! # Functions
+//!
+//! - `decode_transaction`: Decodes the instructions of a legacy transaction.
+//! - `decode_versioned_transaction`: Decodes the instructions of a versioned transaction.
+//! - `decode_confirmed_transaction`: Decodes the instructions and inner instructions of a
+//!   confirmed transaction fetched from an RPC node.
+//!
+//! Each function has a `_with_config` variant decoding the instructions of the program described
+//! by a `ProgramConfig`, e.g. a fork or localnet deployment.
+
+use crate::{config::ProgramConfig, cpi, error};
+use anchor_client::{
+    anchor_lang::{AnchorDeserialize, Discriminator},
+    solana_sdk::{
+        bs58,
+        instruction::{CompiledInstruction, Instruction},
+        pubkey::Pubkey,
+        transaction::{Transaction, VersionedTransaction},
+    },
+};
+use solana_transaction_status::{
+    option_serializer::OptionSerializer, EncodedConfirmedTransactionWithStatusMeta,
+    EncodedTransaction, UiCompiledInstruction, UiInstruction, UiMessage, UiParsedInstruction,
+    UiTransaction,
+};
+use std::str::FromStr;
+
+/// Decoded `initialize` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct InitializeInstruction {
+    /// Global configuration account
+    pub global: Pubkey,
+    /// Account initializing the program
+    pub user: Pubkey,
+}
+
+/// Decoded `setParams` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SetParamsInstruction {
+    /// New account receiving fees
+    pub fee_recipient: Pubkey,
+    /// New initial virtual token reserves
+    pub initial_virtual_token_reserves: u64,
+    /// New initial virtual SOL reserves
+    pub initial_virtual_sol_reserves: u64,
+    /// New initial actual token reserves
+    pub initial_real_token_reserves: u64,
+    /// New total supply of tokens
+    pub token_total_supply: u64,
+    /// New fee in basis points
+    pub fee_basis_points: u64,
+    /// Global configuration account
+    pub global: Pubkey,
+    /// Authority updating the parameters
+    pub user: Pubkey,
+}
+
+/// Decoded `create` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct CreateInstruction {
+    /// Name of the token
+    pub name: String,
+    /// Symbol of the token
+    pub symbol: String,
+    /// Metadata URI of the token
+    pub uri: String,
+    /// Mint of the token
+    pub mint: Pubkey,
+    /// Bonding curve of the token
+    pub bonding_curve: Pubkey,
+    /// Token account of the bonding curve
+    pub associated_bonding_curve: Pubkey,
+    /// Metadata account of the token
+    pub metadata: Pubkey,
+    /// Creator of the token
+    pub user: Pubkey,
+}
+
+/// Decoded `buy` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct BuyInstruction {
+    /// Amount of tokens to buy in base units
+    pub amount: u64,
+    /// Maximum SOL cost including fees in lamports
+    pub max_sol_cost: u64,
+    /// Mint of the token
+    pub mint: Pubkey,
+    /// Account receiving the fee
+    pub fee_recipient: Pubkey,
+    /// Bonding curve of the token
+    pub bonding_curve: Pubkey,
+    /// Token account of the bonding curve
+    pub associated_bonding_curve: Pubkey,
+    /// Token account of the buyer
+    pub associated_user: Pubkey,
+    /// Buyer
+    pub user: Pubkey,
+}
+
+/// Decoded `sell` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SellInstruction {
+    /// Amount of tokens to sell in base units
+    pub amount: u64,
+    /// Minimum SOL output after fees in lamports
+    pub min_sol_output: u64,
+    /// Mint of the token
+    pub mint: Pubkey,
+    /// Account receiving the fee
+    pub fee_recipient: Pubkey,
+    /// Bonding curve of the token
+    pub bonding_curve: Pubkey,
+    /// Token account of the bonding curve
+    pub associated_bonding_curve: Pubkey,
+    /// Token account of the seller
+    pub associated_user: Pubkey,
+    /// Seller
+    pub user: Pubkey,
+}
+
+/// Decoded `withdraw` instruction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct WithdrawInstruction {
+    /// Mint of the token
+    pub mint: Pubkey,
+    /// Bonding curve of the token
+    pub bonding_curve: Pubkey,
+    /// Token account of the bonding curve
+    pub associated_bonding_curve: Pubkey,
+    /// Token account receiving the withdrawn tokens
+    pub associated_user: Pubkey,
+    /// Withdraw authority
+    pub user: Pubkey,
+}
+
+/// Any instruction of the Pump.fun program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PumpFunInstruction {
+    /// The program was initialized
+    Initialize(InitializeInstruction),
+    /// The global parameters were updated
+    SetParams(SetParamsInstruction),
+    /// A token was created
+    Create(CreateInstruction),
+    /// Tokens were bought
+    Buy(BuyInstruction),
+    /// Tokens were sold
+    Sell(SellInstruction),
+    /// The liquidity of a completed curve was withdrawn
+    Withdraw(WithdrawInstruction),
+}
+
+impl PumpFunInstruction {
+    /// Decodes an instruction from its program, accounts and data
+    ///
+    /// # Arguments
+    /// * `program_id` - Program invoked by the instruction
+    /// * `accounts` - Accounts passed to the instruction, in order
+    /// * `data` - Instruction data
+    ///
+    /// # Returns
+    /// The decoded instruction, or None if it is not a known Pump.fun instruction
+    pub fn decode(program_id: &Pubkey, accounts: &[Pubkey], data: &[u8]) -> Option<Self> {
+        Self::decode_with_config(&ProgramConfig::default(), program_id, accounts, data)
+    }
+
+    /// Decodes an instruction of a custom program deployment from its program, accounts and data
+    ///
+    /// # Arguments
+    /// * `config` - Configuration of the program whose instructions are decoded
+    /// * `program_id` - Program invoked by the instruction
+    /// * `accounts` - Accounts passed to the instruction, in order
+    /// * `data` - Instruction data
+    ///
+    /// # Returns
+    /// The decoded instruction, or None if it is not a known instruction of the program
+    pub fn decode_with_config(
+        config: &ProgramConfig,
+        program_id: &Pubkey,
+        accounts: &[Pubkey],
+        data: &[u8],
+    ) -> Option<Self> {
+        if *program_id != config.program_id || data.len() < 8 {
+            return None;
+        }
+
+        let account = |index: usize| accounts.get(index).copied();
+        let (discriminator, mut args) = data.split_at(8);
+        match discriminator {
+            d if d == cpi::instruction::Initialize::DISCRIMINATOR => {
+                Some(Self::Initialize(InitializeInstruction {
+                    global: account(0)?,
+                    user: account(1)?,
+                }))
+            }
+            d if d == cpi::instruction::SetParams::DISCRIMINATOR => {
+                let args = cpi::instruction::SetParams::deserialize(&mut args).ok()?;
+                Some(Self::SetParams(SetParamsInstruction {
+                    fee_recipient: args._fee_recipient,
+                    initial_virtual_token_reserves: args._initial_virtual_token_reserves,
+                    initial_virtual_sol_reserves: args._initial_virtual_sol_reserves,
+                    initial_real_token_reserves: args._initial_real_token_reserves,
+                    token_total_supply: args._token_total_supply,
+                    fee_basis_points: args._fee_basis_points,
+                    global: account(0)?,
+                    user: account(1)?,
+                }))
+            }
+            d if d == cpi::instruction::Create::DISCRIMINATOR => {
+                let args = cpi::instruction::Create::deserialize(&mut args).ok()?;
+                Some(Self::Create(CreateInstruction {
+                    name: args._name,
+                    symbol: args._symbol,
+                    uri: args._uri,
+                    mint: account(0)?,
+                    bonding_curve: account(2)?,
+                    associated_bonding_curve: account(3)?,
+                    metadata: account(6)?,
+                    user: account(7)?,
+                }))
+            }
+            d if d == cpi::instruction::Buy::DISCRIMINATOR => {
+                let args = cpi::instruction::Buy::deserialize(&mut args).ok()?;
+                Some(Self::Buy(BuyInstruction {
+                    amount: args._amount,
+                    max_sol_cost: args._max_sol_cost,
+                    fee_recipient: account(1)?,
+                    mint: account(2)?,
+                    bonding_curve: account(3)?,
+                    associated_bonding_curve: account(4)?,
+                    associated_user: account(5)?,
+                    user: account(6)?,
+                }))
+            }
+            d if d == cpi::instruction::Sell::DISCRIMINATOR => {
+                let args = cpi::instruction::Sell::deserialize(&mut args).ok()?;
+                Some(Self::Sell(SellInstruction {
+                    amount: args._amount,
+                    min_sol_output: args._min_sol_output,
+                    fee_recipient: account(1)?,
+                    mint: account(2)?,
+                    bonding_curve: account(3)?,
+                    associated_bonding_curve: account(4)?,
+                    associated_user: account(5)?,
+                    user: account(6)?,
+                }))
+            }
+            d if d == cpi::instruction::Withdraw::DISCRIMINATOR => {
+                Some(Self::Withdraw(WithdrawInstruction {
+                    mint: account(2)?,
+                    bonding_curve: account(3)?,
+                    associated_bonding_curve: account(4)?,
+                    associated_user: account(5)?,
+                    user: account(6)?,
+                }))
+            }
+            _ => None,
+        }
+    }
+
+    /// Decodes an instruction
+    ///
+    /// # Arguments
+    /// * `instruction` - Instruction to decode
+    ///
+    /// # Returns
+    /// The decoded instruction, or None if it is not a known Pump.fun instruction
+    pub fn from_instruction(instruction: &Instruction) -> Option<Self> {
+        Self::from_instruction_with_config(&ProgramConfig::default(), instruction)
+    }
+
+    /// Decodes an instruction of a custom program deployment
+    ///
+    /// # Arguments
+    /// * `config` - Configuration of the program whose instructions are decoded
+    /// * `instruction` - Instruction to decode
+    ///
+    /// # Returns
+    /// The decoded instruction, or None if it is not a known instruction of the program
+    pub fn from_instruction_with_config(
+        config: &ProgramConfig,
+        instruction: &Instruction,
+    ) -> Option<Self> {
+        let accounts: Vec<Pubkey> = instruction
+            .accounts
+            .iter()
+            .map(|account| account.pubkey)
+            .collect();
+        Self::decode_with_config(
+            config,
+            &instruction.program_id,
+            &accounts,
+            &instruction.data,
+        )
+    }
+
+    /// Decodes a compiled instruction of a transaction
+    ///
+    /// # Arguments
+    /// * `account_keys` - Account keys of the transaction, including loaded addresses
+    /// * `instruction` - Compiled instruction
+    ///
+    /// # Returns
+    /// The decoded instruction, or None if it is not a known Pump.fun instruction
+    pub fn from_compiled_instruction(
+        account_keys: &[Pubkey],
+        instruction: &CompiledInstruction,
+    ) -> Option<Self> {
+        Self::from_compiled_instruction_with_config(
+            &ProgramConfig::default(),
+            account_keys,
+            instruction,
+        )
+    }
+
+    /// Decodes a compiled instruction of a transaction for a custom program deployment
+    ///
+    /// # Arguments
+    /// * `config` - Configuration of the program whose instructions are decoded
+    /// * `account_keys` - Account keys of the transaction, including loaded addresses
+    /// * `instruction` - Compiled instruction
+    ///
+    /// # Returns
+    /// The decoded instruction, or None if it is not a known instruction of the program
+    pub fn from_compiled_instruction_with_config(
+        config: &ProgramConfig,
+        account_keys: &[Pubkey],
+        instruction: &CompiledInstruction,
+    ) -> Option<Self> {
+        let program_id = account_keys.get(instruction.program_id_index as usize)?;
+        let accounts = instruction
+            .accounts
+            .iter()
+            .map(|index| account_keys.get(*index as usize).copied())
+            .collect::<Option<Vec<Pubkey>>>()?;
+        Self::decode_with_config(config, program_id, &accounts, &instruction.data)
+    }
+}
+
+/// A Pump.fun instruction along with its position in a transaction
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct DecodedInstruction {
+    /// Index of the top-level instruction that is or invoked this instruction
+    pub index: usize,
+    /// Index among the inner instructions of the top-level instruction, or None if top-level
+    pub inner_index: Option<usize>,
+    /// Decoded instruction
+    pub instruction: PumpFunInstruction,
+}
+
+/// Decodes the Pump.fun instructions of a legacy transaction
+///
+/// # Arguments
+/// * `transaction` - Transaction to decode
+///
+/// # Returns
+/// The decoded top-level instructions in order
+pub fn decode_transaction(transaction: &Transaction) -> Vec<DecodedInstruction> {
+    decode_transaction_with_config(&ProgramConfig::default(), transaction)
+}
+
+/// Decodes the instructions of a custom program deployment in a legacy transaction
+///
+/// # Arguments
+/// * `config` - Configuration of the program whose instructions are decoded
+/// * `transaction` - Transaction to decode
+///
+/// # Returns
+/// The decoded top-level instructions in order
+pub fn decode_transaction_with_config(
+    config: &ProgramConfig,
+    transaction: &Transaction,
+) -> Vec<DecodedInstruction> {
+    decode_compiled_instructions(
+        config,
+        &transaction.message.account_keys,
+        &transaction.message.instructions,
+        &[],
+    )
+}
+
+/// Decodes the Pump.fun instructions of a versioned transaction
+///
+/// Only the static account keys are known, so instructions using accounts loaded from address
+/// lookup tables are skipped. Use `decode_confirmed_transaction` to resolve them.
+///
+/// # Arguments
+/// * `transaction` - Transaction to decode
+///
+/// # Returns
+/// The decoded top-level instructions in order
+pub fn decode_versioned_transaction(transaction: &VersionedTransaction) -> Vec<DecodedInstruction> {
+    decode_versioned_transaction_with_config(&ProgramConfig::default(), transaction)
+}
+
+/// Decodes the instructions of a custom program deployment in a versioned transaction
+///
+/// # Arguments
+/// * `config` - Configuration of the program whose instructions are decoded
+/// * `transaction` - Transaction to decode
+///
+/// # Returns
+/// The decoded top-level instructions in order
+pub fn decode_versioned_transaction_with_config(
+    config: &ProgramConfig,
+    transaction: &VersionedTransaction,
+) -> Vec<DecodedInstruction> {
+    decode_compiled_instructions(
+        config,
+        transaction.message.static_account_keys(),
+        transaction.message.instructions(),
+        &[],
+    )
+}
+
+/// Decodes the Pump.fun instructions of a confirmed transaction, including inner instructions
+///
+/// The transaction can be fetched with any encoding but `accounts`. With `jsonParsed`, Pump.fun
+/// instructions are reported partially decoded and are decoded like compiled instructions.
+///
+/// # Arguments
+/// * `transaction` - Confirmed transaction returned by `getTransaction`
+///
+/// # Returns
+/// The decoded instructions in execution order, or a ClientError if the transaction cannot be decoded
+#[allow(clippy::result_large_err)]
+pub fn decode_confirmed_transaction(
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<Vec<DecodedInstruction>, error::ClientError> {
+    decode_confirmed_transaction_with_config(&ProgramConfig::default(), transaction)
+}
+
+/// Decodes the instructions of a custom program deployment in a confirmed transaction,
+/// including inner instructions
+///
+/// # Arguments
+/// * `config` - Configuration of the program whose instructions are decoded
+/// * `transaction` - Confirmed transaction returned by `getTransaction`
+///
+/// # Returns
+/// The decoded instructions in execution order, or a ClientError if the transaction cannot be decoded
+#[allow(clippy::result_large_err)]
+pub fn decode_confirmed_transaction_with_config(
+    config: &ProgramConfig,
+    transaction: &EncodedConfirmedTransactionWithStatusMeta,
+) -> Result<Vec<DecodedInstruction>, error::ClientError> {
+    let (mut account_keys, instructions) = match &transaction.transaction.transaction {
+        EncodedTransaction::Json(UiTransaction {
+            message: UiMessage::Raw(message),
+            ..
+        }) => (
+            parse_pubkeys(&message.account_keys).ok_or(invalid_account_key())?,
+            message
+                .instructions
+                .iter()
+                .map(compile_ui_compiled_instruction)
+                .collect::<Option<Vec<_>>>()
+                .ok_or(invalid_instruction_data())?,
+        ),
+        // Parsed messages list the loaded addresses along with the static keys
+        EncodedTransaction::Json(UiTransaction {
+            message: UiMessage::Parsed(message),
+            ..
+        }) => {
+            let mut account_keys = message
+                .account_keys
+                .iter()
+                .map(|account| Pubkey::from_str(&account.pubkey).ok())
+                .collect::<Option<Vec<_>>>()
+                .ok_or(invalid_account_key())?;
+            let instructions = message
+                .instructions
+                .iter()
+                .map(|instruction| compile_ui_instruction(config, &mut account_keys, instruction))
+                .collect::<Result<Vec<_>, _>>()?;
+            (account_keys, instructions)
+        }
+        EncodedTransaction::Accounts(_) => {
+            return Err(error::ClientError::InvalidInput(
+                "Transactions encoded as accounts cannot be decoded",
+            ))
+        }
+        encoded => {
+            let decoded = encoded.decode().ok_or(error::ClientError::InvalidInput(
+                "Invalid transaction encoding",
+            ))?;
+            (
+                decoded.message.static_account_keys().to_vec(),
+                decoded.message.instructions().to_vec(),
+            )
+        }
+    };
+
+    let mut inner_instructions = Vec::new();
+    if let Some(meta) = &transaction.transaction.meta {
+        // Loaded addresses follow the static keys, writable ones first
+        if let OptionSerializer::Some(loaded) = &meta.loaded_addresses {
+            account_keys.extend(parse_pubkeys(&loaded.writable).ok_or(invalid_account_key())?);
+            account_keys.extend(parse_pubkeys(&loaded.readonly).ok_or(invalid_account_key())?);
+        }
+
+        if let OptionSerializer::Some(inner) = &meta.inner_instructions {
+            for group in inner {
+                let compiled = group
+                    .instructions
+                    .iter()
+                    .map(|instruction| {
+                        compile_ui_instruction(config, &mut account_keys, instruction)
+                    })
+                    .collect::<Result<Vec<_>, _>>()?;
+                inner_instructions.push((group.index as usize, compiled));
+            }
+        }
+    }
+
+    Ok(decode_compiled_instructions(
+        config,
+        &account_keys,
+        &instructions,
+        &inner_instructions,
+    ))
+}
+
+fn invalid_account_key() -> error::ClientError {
+    error::ClientError::InvalidInput("Invalid account key")
+}
+
+fn invalid_instruction_data() -> error::ClientError {
+    error::ClientError::InvalidInput("Invalid instruction data")
+}
+
+/// Decodes top-level instructions, each followed by its inner instructions
+fn decode_compiled_instructions(
+    config: &ProgramConfig,
+    account_keys: &[Pubkey],
+    instructions: &[CompiledInstruction],
+    inner_instructions: &[(usize, Vec<CompiledInstruction>)],
+) -> Vec<DecodedInstruction> {
+    let mut decoded = Vec::new();
+
+    for (index, instruction) in instructions.iter().enumerate() {
+        if let Some(instruction) = PumpFunInstruction::from_compiled_instruction_with_config(
+            config,
+            account_keys,
+            instruction,
+        ) {
+            decoded.push(DecodedInstruction {
+                index,
+                inner_index: None,
+                instruction,
+            });
+        }
+
+        let inner = inner_instructions
+            .iter()
+            .filter(|(outer, _)| *outer == index)
+            .flat_map(|(_, inner)| inner.iter());
+        for (inner_index, instruction) in inner.enumerate() {
+            if let Some(instruction) = PumpFunInstruction::from_compiled_instruction_with_config(
+                config,
+                account_keys,
+                instruction,
+            ) {
+                decoded.push(DecodedInstruction {
+                    index,
+                    inner_index: Some(inner_index),
+                    instruction,
+                });
+            }
+        }
+    }
+
+    decoded
+}
+
+/// Converts an RPC instruction into a compiled instruction against the account keys
+///
+/// Partially decoded instructions are compiled by looking up, or appending, their account keys.
+/// Fully parsed instructions belong to programs known to the RPC node and are kept as compiled
+/// instructions without data, so the positions of the remaining inner instructions are preserved.
+/// The RPC node does not parse Pump.fun instructions, so a parsed instruction of the program is
+/// reported as an error rather than dropped.
+#[allow(clippy::result_large_err)]
+fn compile_ui_instruction(
+    config: &ProgramConfig,
+    account_keys: &mut Vec<Pubkey>,
+    instruction: &UiInstruction,
+) -> Result<CompiledInstruction, error::ClientError> {
+    match instruction {
+        UiInstruction::Compiled(compiled) => {
+            compile_ui_compiled_instruction(compiled).ok_or(invalid_instruction_data())
+        }
+        UiInstruction::Parsed(UiParsedInstruction::PartiallyDecoded(instruction)) => {
+            let program_id_index = account_index(account_keys, &instruction.program_id)?;
+            let accounts = instruction
+                .accounts
+                .iter()
+                .map(|account| account_index(account_keys, account))
+                .collect::<Result<Vec<_>, _>>()?;
+            let data = bs58::decode(&instruction.data)
+                .into_vec()
+                .map_err(|_| invalid_instruction_data())?;
+
+            Ok(CompiledInstruction::new_from_raw_parts(
+                program_id_index,
+                data,
+                accounts,
+            ))
+        }
+        UiInstruction::Parsed(UiParsedInstruction::Parsed(instruction)) => {
+            let program_id_index = account_index(account_keys, &instruction.program_id)?;
+            if account_keys[program_id_index as usize] == config.program_id {
+                return Err(error::ClientError::InvalidInput(
+                    "Parsed Pump.fun instructions cannot be decoded",
+                ));
+            }
+
+            Ok(CompiledInstruction::new_from_raw_parts(
+                program_id_index,
+                Vec::new(),
+                Vec::new(),
+            ))
+        }
+    }
+}
+
+/// Converts an RPC compiled instruction with base58 data into a compiled instruction
+fn compile_ui_compiled_instruction(
+    instruction: &UiCompiledInstruction,
+) -> Option<CompiledInstruction> {
+    let data = bs58::decode(&instruction.data).into_vec().ok()?;
+
+    Some(CompiledInstruction::new_from_raw_parts(
+        instruction.program_id_index,
+        data,
+        instruction.accounts.clone(),
+    ))
+}
+
+/// Gets the index of a base58 encoded account key, appending it if it is not listed yet
+#[allow(clippy::result_large_err)]
+fn account_index(account_keys: &mut Vec<Pubkey>, key: &str) -> Result<u8, error::ClientError> {
+    let key = Pubkey::from_str(key).map_err(|_| invalid_account_key())?;
+    let index = match account_keys.iter().position(|k| *k == key) {
+        Some(index) => index,
+        None => {
+            account_keys.push(key);
+            account_keys.len() - 1
+        }
+    };
+
+    u8::try_from(index).map_err(|_| invalid_account_key())
+}
+
+/// Parses base58 encoded account keys
+fn parse_pubkeys(keys: &[String]) -> Option<Vec<Pubkey>> {
+    keys.iter().map(|key| Pubkey::from_str(key).ok()).collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{constants::accounts::PUMPFUN, instruction, PumpFun};
+    use anchor_client::solana_sdk::{
+        address_lookup_table_account::AddressLookupTableAccount,
+        hash::Hash,
+        instruction::AccountMeta,
+        message::{v0, Message, VersionedMessage},
+        signature::Signature,
+        system_instruction,
+    };
+    use anchor_spl::associated_token::get_associated_token_address;
+    use solana_transaction_status::{
+        ConfirmedTransactionWithStatusMeta, InnerInstruction, InnerInstructions,
+        TransactionStatusMeta, TransactionWithStatusMeta, UiTransactionEncoding,
+        VersionedTransactionWithStatusMeta,
+    };
+
+    /// Compiles an instruction against a list of account keys
+    fn compile(account_keys: &[Pubkey], instruction: &Instruction) -> CompiledInstruction {
+        let position = |key: &Pubkey| account_keys.iter().position(|k| k == key).unwrap() as u8;
+        CompiledInstruction::new_from_raw_parts(
+            position(&instruction.program_id),
+            instruction.data.clone(),
+            instruction
+                .accounts
+                .iter()
+                .map(|account| position(&account.pubkey))
+                .collect(),
+        )
+    }
+
+    #[test]
+    fn test_decode_transaction() {
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let fee_recipient = Pubkey::new_unique();
+        let bonding_curve = PumpFun::get_bonding_curve_pda(&mint).unwrap();
+
+        let create = instruction::create(
+            &user,
+            &mint,
+            cpi::instruction::Create {
+                _name: "Test Token".to_string(),
+                _symbol: "TEST".to_string(),
+                _uri: "https://example.com".to_string(),
+            },
+        );
+        let buy = instruction::buy(
+            &user,
+            &mint,
+            &fee_recipient,
+            cpi::instruction::Buy {
+                _amount: 1_000,
+                _max_sol_cost: 2_000,
+            },
+        );
+        let memo = Instruction::new_with_bytes(Pubkey::new_unique(), b"memo", vec![]);
+        let transaction =
+            Transaction::new_unsigned(Message::new(&[create, memo, buy], Some(&user)));
+
+        let decoded = decode_transaction(&transaction);
+        assert_eq!(
+            decoded,
+            vec![
+                DecodedInstruction {
+                    index: 0,
+                    inner_index: None,
+                    instruction: PumpFunInstruction::Create(CreateInstruction {
+                        name: "Test Token".to_string(),
+                        symbol: "TEST".to_string(),
+                        uri: "https://example.com".to_string(),
+                        mint,
+                        bonding_curve,
+                        associated_bonding_curve: get_associated_token_address(
+                            &bonding_curve,
+                            &mint
+                        ),
+                        metadata: PumpFun::get_metadata_pda(&mint),
+                        user,
+                    }),
+                },
+                DecodedInstruction {
+                    index: 2,
+                    inner_index: None,
+                    instruction: PumpFunInstruction::Buy(BuyInstruction {
+                        amount: 1_000,
+                        max_sol_cost: 2_000,
+                        mint,
+                        fee_recipient,
+                        bonding_curve,
+                        associated_bonding_curve: get_associated_token_address(
+                            &bonding_curve,
+                            &mint
+                        ),
+                        associated_user: get_associated_token_address(&user, &mint),
+                        user,
+                    }),
+                },
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_instructions() {
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let sell = instruction::sell(
+            &user,
+            &mint,
+            &Pubkey::new_unique(),
+            cpi::instruction::Sell {
+                _amount: 1_000,
+                _min_sol_output: 500,
+            },
+        );
+        assert!(matches!(
+            PumpFunInstruction::from_instruction(&sell),
+            Some(PumpFunInstruction::Sell(_))
+        ));
+
+        // Other programs
+        let mut other = sell.clone();
+        other.program_id = Pubkey::new_unique();
+        assert!(PumpFunInstruction::from_instruction(&other).is_none());
+
+        // Unknown discriminators
+        let mut unknown = sell.clone();
+        unknown.data[0] ^= 0xff;
+        assert!(PumpFunInstruction::from_instruction(&unknown).is_none());
+
+        // Truncated arguments
+        let mut truncated = sell.clone();
+        truncated.data.truncate(12);
+        assert!(PumpFunInstruction::from_instruction(&truncated).is_none());
+
+        // Missing accounts
+        let mut missing = sell;
+        missing.accounts.truncate(6);
+        assert!(PumpFunInstruction::from_instruction(&missing).is_none());
+    }
+
+    #[test]
+    fn test_decode_with_config() {
+        let config = ProgramConfig::new(Pubkey::new_unique());
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let buy = instruction::buy_with_config(
+            &config,
+            &user,
+            &mint,
+            &Pubkey::new_unique(),
+            cpi::instruction::Buy {
+                _amount: 1_000,
+                _max_sol_cost: 2_000,
+            },
+        );
+
+        match PumpFunInstruction::from_instruction_with_config(&config, &buy) {
+            Some(PumpFunInstruction::Buy(decoded)) => {
+                assert_eq!(decoded.amount, 1_000);
+                assert_eq!(decoded.mint, mint);
+                assert_eq!(
+                    decoded.bonding_curve,
+                    config.bonding_curve_pda(&mint).unwrap()
+                );
+                assert_eq!(decoded.user, user);
+            }
+            other => panic!("unexpected instruction: {:?}", other),
+        }
+        assert!(PumpFunInstruction::from_instruction(&buy).is_none());
+
+        let transaction = Transaction::new_unsigned(Message::new(&[buy], Some(&user)));
+        assert_eq!(
+            decode_transaction_with_config(&config, &transaction).len(),
+            1
+        );
+        assert!(decode_transaction(&transaction).is_empty());
+    }
+
+    #[test]
+    fn test_decode_confirmed_transaction_inner_instructions() {
+        let user = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let fee_recipient = Pubkey::new_unique();
+        let router = Pubkey::new_unique();
+        let lookup_table = Pubkey::new_unique();
+
+        // A router invokes sell with the fee recipient loaded from a lookup table
+        let sell = instruction::sell(
+            &user,
+            &mint,
+            &fee_recipient,
+            cpi::instruction::Sell {
+                _amount: 1_000,
+                _min_sol_output: 500,
+            },
+        );
+        let mut accounts = sell.accounts.clone();
+        accounts.push(AccountMeta::new_readonly(PUMPFUN, false));
+        let route = Instruction::new_with_bytes(router, &[1], accounts);
+        let message = v0::Message::try_compile(
+            &user,
+            &[route],
+            &[AddressLookupTableAccount {
+                key: lookup_table,
+                addresses: vec![fee_recipient],
+            }],
+            Hash::default(),
+        )
+        .unwrap();
+        assert!(!message.account_keys.contains(&fee_recipient));
+
+        // The router pays a fee through the system program before selling
+        let transfer = system_instruction::transfer(&user, &fee_recipient, 1_000);
+        let mut account_keys = message.account_keys.clone();
+        account_keys.push(fee_recipient);
+        let meta = TransactionStatusMeta {
+            inner_instructions: Some(vec![InnerInstructions {
+                index: 0,
+                instructions: vec![
+                    InnerInstruction {
+                        instruction: compile(&account_keys, &transfer),
+                        stack_height: Some(2),
+                    },
+                    InnerInstruction {
+                        instruction: compile(&account_keys, &sell),
+                        stack_height: Some(2),
+                    },
+                ],
+            }]),
+            loaded_addresses: v0::LoadedAddresses {
+                writable: vec![fee_recipient],
+                readonly: vec![],
+            },
+            ..TransactionStatusMeta::default()
+        };
+        let transaction = VersionedTransaction {
+            signatures: vec![Signature::default()],
+            message: VersionedMessage::V0(message),
+        };
+
+        // Static keys alone cannot resolve the fee recipient
+        assert!(decode_versioned_transaction(&transaction).is_empty());
+
+        // Parsed transactions report the Pump.fun instructions partially decoded
+        for encoding in [
+            UiTransactionEncoding::Base64,
+            UiTransactionEncoding::Json,
+            UiTransactionEncoding::JsonParsed,
+        ] {
+            let confirmed = ConfirmedTransactionWithStatusMeta {
+                slot: 1,
+                tx_with_meta: TransactionWithStatusMeta::Complete(
+                    VersionedTransactionWithStatusMeta {
+                        transaction: transaction.clone(),
+                        meta: meta.clone(),
+                    },
+                ),
+                block_time: None,
+            }
+            .encode(encoding, Some(0))
+            .unwrap();
+
+            let decoded = decode_confirmed_transaction(&confirmed).unwrap();
+            assert_eq!(decoded.len(), 1);
+            assert_eq!(decoded[0].index, 0);
+            assert_eq!(decoded[0].inner_index, Some(1));
+            match &decoded[0].instruction {
+                PumpFunInstruction::Sell(sell) => {
+                    assert_eq!(sell.amount, 1_000);
+                    assert_eq!(sell.min_sol_output, 500);
+                    assert_eq!(sell.mint, mint);
+                    assert_eq!(sell.fee_recipient, fee_recipient);
+                    assert_eq!(sell.user, user);
+                }
+                other => panic!("unexpected instruction: {:?}", other),
+            }
+        }
+    }
+}
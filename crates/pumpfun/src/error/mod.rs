@@ -10,26 +10,164 @@
 //! - `BondingCurveNotFound`: The bonding curve account was not found.
 //! - `BondingCurveError`: An error occurred while interacting with the bonding curve.
 //! - `BorshError`: An error occurred while serializing or deserializing data using Borsh.
+//! - `InvalidAccountDiscriminator`: Account data does not start with the expected Anchor discriminator.
 //! - `SolanaClientError`: An error occurred while interacting with the Solana RPC client.
+//! - `PubsubClientError`: An error occurred while interacting with the Solana websocket client.
 //! - `UploadMetadataError`: An error occurred while uploading metadata to IPFS.
 //! - `AnchorClientError`: An error occurred while interacting with the Anchor client.
 //! - `InvalidInput`: Invalid input parameters were provided.
 //! - `InsufficientFunds`: Insufficient funds for a transaction.
+//! - `ProgramError`: The Pump.fun program rejected the transaction with one of its error codes.
 //! - `SimulationError`: Transaction simulation failed.
-//! - `RateLimitExceeded`: Rate limit exceeded.
+//! - `RateLimitExceeded`: The RPC node or metadata API rate limited the request.
+//!
+//! Bonding curve calculations report failures with the `CurveError` enum, which is wrapped by
+//! `ClientError::BondingCurveError`.
+//!
+//! Transactions rejected by the program with one of its custom error codes surface as
+//! `ClientError::ProgramError`, whether they fail when sent or during simulation. RPC requests
+//! rejected as rate limited surface as `ClientError::RateLimitExceeded`, after the retries of the
+//! client's rate limit run out when one is configured.
+
+use anchor_client::{
+    solana_client,
+    solana_sdk::{instruction::InstructionError, transaction::TransactionError},
+};
+
+/// Errors returned by bonding curve calculations
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum CurveError {
+    /// The bonding curve is complete and no longer trades
+    Complete,
+    /// An intermediate or final value does not fit in its integer type
+    Overflow,
+    /// The curve does not hold enough reserves to fill the trade
+    InsufficientLiquidity,
+    /// The curve has empty virtual reserves
+    ZeroReserves,
+}
+
+impl std::fmt::Display for CurveError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Complete => write!(f, "Curve is complete"),
+            Self::Overflow => write!(f, "Arithmetic overflow"),
+            Self::InsufficientLiquidity => write!(f, "Not enough liquidity in curve"),
+            Self::ZeroReserves => write!(f, "Curve has zero virtual reserves"),
+        }
+    }
+}
+
+impl std::error::Error for CurveError {}
+
+/// Custom errors returned by the Pump.fun program, numbered from 6000 as in the IDL
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum ProgramError {
+    /// The given account is not authorized to execute this instruction
+    NotAuthorized = 6000,
+    /// The program is already initialized
+    AlreadyInitialized = 6001,
+    /// Too much SOL required to buy the given amount of tokens
+    TooMuchSolRequired = 6002,
+    /// Too little SOL received to sell the given amount of tokens
+    TooLittleSolReceived = 6003,
+    /// The mint does not match the bonding curve
+    MintDoesNotMatchBondingCurve = 6004,
+    /// The bonding curve has completed and liquidity migrated to Raydium
+    BondingCurveComplete = 6005,
+    /// The bonding curve has not completed
+    BondingCurveNotComplete = 6006,
+    /// The program is not initialized
+    NotInitialized = 6007,
+    /// Withdraw too frequent
+    WithdrawTooFrequent = 6008,
+}
+
+impl ProgramError {
+    /// Gets the error for a custom error code
+    ///
+    /// # Arguments
+    /// * `code` - Custom error code returned by the program
+    ///
+    /// # Returns
+    /// Some(ProgramError) for a code declared by the program, or None otherwise
+    pub fn from_code(code: u32) -> Option<Self> {
+        match code {
+            6000 => Some(Self::NotAuthorized),
+            6001 => Some(Self::AlreadyInitialized),
+            6002 => Some(Self::TooMuchSolRequired),
+            6003 => Some(Self::TooLittleSolReceived),
+            6004 => Some(Self::MintDoesNotMatchBondingCurve),
+            6005 => Some(Self::BondingCurveComplete),
+            6006 => Some(Self::BondingCurveNotComplete),
+            6007 => Some(Self::NotInitialized),
+            6008 => Some(Self::WithdrawTooFrequent),
+            _ => None,
+        }
+    }
+
+    /// Gets the error a transaction failed with, if it is a program error
+    ///
+    /// Only the Pump.fun program uses custom codes from 6000 in the transactions built by this
+    /// crate; the system, token and associated token programs use lower codes.
+    ///
+    /// # Arguments
+    /// * `err` - Error the transaction failed with
+    ///
+    /// # Returns
+    /// Some(ProgramError) if an instruction failed with a code declared by the program, or None otherwise
+    pub fn from_transaction_error(err: &TransactionError) -> Option<Self> {
+        match err {
+            TransactionError::InstructionError(_, InstructionError::Custom(code)) => {
+                Self::from_code(*code)
+            }
+            _ => None,
+        }
+    }
+
+    /// Gets the custom error code of the error
+    pub fn code(&self) -> u32 {
+        *self as u32
+    }
+
+    /// Whether the error is a slippage check failing because the price moved
+    pub fn is_slippage(&self) -> bool {
+        matches!(self, Self::TooMuchSolRequired | Self::TooLittleSolReceived)
+    }
+}
+
+impl std::fmt::Display for ProgramError {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::NotAuthorized => write!(f, "Not authorized to execute this instruction"),
+            Self::AlreadyInitialized => write!(f, "Program is already initialized"),
+            Self::TooMuchSolRequired => write!(f, "Too much SOL required to buy tokens"),
+            Self::TooLittleSolReceived => write!(f, "Too little SOL received to sell tokens"),
+            Self::MintDoesNotMatchBondingCurve => write!(f, "Mint does not match bonding curve"),
+            Self::BondingCurveComplete => write!(f, "Bonding curve is complete"),
+            Self::BondingCurveNotComplete => write!(f, "Bonding curve is not complete"),
+            Self::NotInitialized => write!(f, "Program is not initialized"),
+            Self::WithdrawTooFrequent => write!(f, "Withdraw too frequent"),
+        }
+    }
+}
 
-use anchor_client::solana_client;
+impl std::error::Error for ProgramError {}
 
 #[derive(Debug)]
 pub enum ClientError {
     /// Bonding curve account was not found
     BondingCurveNotFound,
     /// Error related to bonding curve operations
-    BondingCurveError(&'static str),
+    BondingCurveError(CurveError),
     /// Error deserializing data using Borsh
     BorshError(std::io::Error),
+    /// Account data does not belong to the named account type
+    InvalidAccountDiscriminator(&'static str),
     /// Error from Solana RPC client
     SolanaClientError(solana_client::client_error::ClientError),
+    /// Error from Solana websocket client
+    PubsubClientError(solana_client::nonblocking::pubsub_client::PubsubClientError),
     /// Error uploading metadata
     UploadMetadataError(Box<dyn std::error::Error>),
     /// Error from Anchor client
@@ -38,6 +176,8 @@ pub enum ClientError {
     InvalidInput(&'static str),
     /// Insufficient funds for transaction
     InsufficientFunds,
+    /// Program rejected the transaction with a custom error
+    ProgramError(ProgramError),
     /// Transaction simulation failed
     SimulationError(String),
     /// Rate limit exceeded
@@ -50,11 +190,20 @@ impl std::fmt::Display for ClientError {
             Self::BondingCurveNotFound => write!(f, "Bonding curve not found"),
             Self::BondingCurveError(msg) => write!(f, "Bonding curve error: {}", msg),
             Self::BorshError(err) => write!(f, "Borsh serialization error: {}", err),
+            Self::InvalidAccountDiscriminator(name) => {
+                write!(
+                    f,
+                    "Invalid account discriminator: expected {} account",
+                    name
+                )
+            }
             Self::SolanaClientError(err) => write!(f, "Solana client error: {}", err),
+            Self::PubsubClientError(err) => write!(f, "Solana websocket client error: {}", err),
             Self::UploadMetadataError(err) => write!(f, "Metadata upload error: {}", err),
             Self::AnchorClientError(err) => write!(f, "Anchor client error: {}", err),
             Self::InvalidInput(msg) => write!(f, "Invalid input: {}", msg),
             Self::InsufficientFunds => write!(f, "Insufficient funds for transaction"),
+            Self::ProgramError(err) => write!(f, "Program error {}: {}", err.code(), err),
             Self::SimulationError(msg) => write!(f, "Transaction simulation failed: {}", msg),
             Self::RateLimitExceeded => write!(f, "Rate limit exceeded"),
         }
@@ -66,21 +215,109 @@ impl std::error::Error for ClientError {
         match self {
             Self::BorshError(err) => Some(err),
             Self::SolanaClientError(err) => Some(err),
+            Self::PubsubClientError(err) => Some(err),
             Self::UploadMetadataError(err) => Some(err.as_ref()),
             Self::AnchorClientError(err) => Some(err),
+            Self::BondingCurveError(err) => Some(err),
+            Self::ProgramError(err) => Some(err),
             _ => None,
         }
     }
 }
 
 impl From<solana_client::client_error::ClientError> for ClientError {
+    /// Converts an RPC client error, surfacing rate limited requests as `RateLimitExceeded` and
+    /// transactions rejected by the program as `ProgramError`
     fn from(err: solana_client::client_error::ClientError) -> Self {
-        Self::SolanaClientError(err)
+        if crate::ratelimit::is_retries_exhausted(&err) || crate::ratelimit::is_rate_limited(&err) {
+            return Self::RateLimitExceeded;
+        }
+
+        match err
+            .get_transaction_error()
+            .as_ref()
+            .and_then(ProgramError::from_transaction_error)
+        {
+            Some(program_error) => Self::ProgramError(program_error),
+            None => Self::SolanaClientError(err),
+        }
+    }
+}
+
+impl From<solana_client::nonblocking::pubsub_client::PubsubClientError> for ClientError {
+    fn from(err: solana_client::nonblocking::pubsub_client::PubsubClientError) -> Self {
+        Self::PubsubClientError(err)
+    }
+}
+
+impl From<CurveError> for ClientError {
+    fn from(err: CurveError) -> Self {
+        Self::BondingCurveError(err)
+    }
+}
+
+impl From<ProgramError> for ClientError {
+    fn from(err: ProgramError) -> Self {
+        Self::ProgramError(err)
     }
 }
 
 impl From<anchor_client::ClientError> for ClientError {
+    /// Converts an Anchor client error, surfacing transactions rejected by the program as `ProgramError`
     fn from(err: anchor_client::ClientError) -> Self {
-        Self::AnchorClientError(err)
+        let program_error = match &err {
+            anchor_client::ClientError::SolanaClientError(err) => err
+                .get_transaction_error()
+                .as_ref()
+                .and_then(ProgramError::from_transaction_error),
+            _ => None,
+        };
+
+        match program_error {
+            Some(program_error) => Self::ProgramError(program_error),
+            None => Self::AnchorClientError(err),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::Value;
+
+    #[test]
+    fn test_program_error_codes_match_idl() {
+        let idl: Value =
+            serde_json::from_str(include_str!("../../../pumpfun-cpi/idl.json")).unwrap();
+        let errors = idl["errors"].as_array().unwrap();
+        assert_eq!(errors.len(), 9);
+
+        for error in errors {
+            let code = error["code"].as_u64().unwrap() as u32;
+            let program_error = ProgramError::from_code(code).unwrap();
+            assert_eq!(program_error.code(), code);
+            assert_eq!(
+                format!("{:?}", program_error),
+                error["name"].as_str().unwrap()
+            );
+        }
+
+        assert_eq!(ProgramError::from_code(5999), None);
+        assert_eq!(ProgramError::from_code(6009), None);
+    }
+
+    #[test]
+    fn test_program_error_from_transaction_error() {
+        let err = TransactionError::InstructionError(2, InstructionError::Custom(6002));
+        let program_error = ProgramError::from_transaction_error(&err).unwrap();
+        assert_eq!(program_error, ProgramError::TooMuchSolRequired);
+        assert!(program_error.is_slippage());
+
+        let err = TransactionError::InstructionError(0, InstructionError::Custom(1));
+        assert_eq!(ProgramError::from_transaction_error(&err), None);
+        assert_eq!(
+            ProgramError::from_transaction_error(&TransactionError::AccountNotFound),
+            None
+        );
     }
 }
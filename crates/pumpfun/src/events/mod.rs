@@ -0,0 +1,599 @@
+//! Events emitted by the Pump.fun Solana Program
+//!
+//! This module contains the definitions for the events emitted by the Pump.fun program and helpers
+//! for decoding them from transaction logs and self-CPI event instructions.
+//!
+//! # Events
+//!
+//! - `CreateEvent`: Emitted when a new token and its bonding curve are created.
+//! - `TradeEvent`: Emitted when tokens are bought from or sold to a bonding curve.
+//! - `CompleteEvent`: Emitted when a bonding curve runs out of tokens and completes.
+//! - `SetParamsEvent`: Emitted when the global parameters are updated.
+//!
+//! Events are emitted as Anchor "Program data:" log lines, which contain the base64 encoded
+//! event discriminator followed by the Borsh serialized event. Events emitted through a self-CPI
+//! are instead carried by an inner instruction to the program, signed by the event authority,
+//! whose data is the Anchor event instruction tag followed by the same bytes.
+//!
+//! The `_with_config` variants decode the events of the program described by a `ProgramConfig`,
+//! e.g. a fork or localnet deployment; the others target the program deployed on mainnet.
+
+use crate::config::ProgramConfig;
+use anchor_client::solana_sdk::{
+    instruction::{CompiledInstruction, Instruction},
+    pubkey::Pubkey,
+};
+use base64::{engine::general_purpose::STANDARD, Engine};
+use borsh::{BorshDeserialize, BorshSerialize};
+
+/// Prefix of the log lines that carry Anchor event data
+pub const PROGRAM_DATA: &str = "Program data: ";
+
+/// Prefix of the log lines recording program invocations and their completion
+const PROGRAM_LOG: &str = "Program ";
+
+/// Tag prefixed to the data of Anchor self-CPI event instructions
+pub const EVENT_IX_TAG: [u8; 8] = [228, 69, 165, 46, 81, 203, 154, 29];
+
+/// Represents the event emitted when a new token is created
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CreateEvent {
+    /// Name of the token
+    pub name: String,
+    /// Token symbol
+    pub symbol: String,
+    /// Metadata URI of the token
+    pub uri: String,
+    /// Mint of the token (stored as a byte array)
+    pub mint_bytes: [u8; 32],
+    /// Bonding curve of the token (stored as a byte array)
+    pub bonding_curve_bytes: [u8; 32],
+    /// Creator of the token (stored as a byte array)
+    pub user_bytes: [u8; 32],
+}
+
+impl CreateEvent {
+    /// Anchor discriminator of the event
+    pub const DISCRIMINATOR: [u8; 8] = [27, 114, 169, 77, 222, 235, 99, 118];
+
+    /// Get the mint pubkey
+    pub fn mint(&self) -> Pubkey {
+        Pubkey::new_from_array(self.mint_bytes)
+    }
+
+    /// Get the bonding curve pubkey
+    pub fn bonding_curve(&self) -> Pubkey {
+        Pubkey::new_from_array(self.bonding_curve_bytes)
+    }
+
+    /// Get the creator pubkey
+    pub fn user(&self) -> Pubkey {
+        Pubkey::new_from_array(self.user_bytes)
+    }
+}
+
+/// Represents the event emitted when tokens are bought or sold
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct TradeEvent {
+    /// Mint of the traded token (stored as a byte array)
+    pub mint_bytes: [u8; 32],
+    /// Amount of SOL exchanged in lamports
+    pub sol_amount: u64,
+    /// Amount of tokens exchanged in base units
+    pub token_amount: u64,
+    /// Whether the trade was a buy
+    pub is_buy: bool,
+    /// Trader (stored as a byte array)
+    pub user_bytes: [u8; 32],
+    /// Unix timestamp of the trade
+    pub timestamp: i64,
+    /// Virtual SOL reserves after the trade
+    pub virtual_sol_reserves: u64,
+    /// Virtual token reserves after the trade
+    pub virtual_token_reserves: u64,
+    /// Actual SOL reserves after the trade
+    pub real_sol_reserves: u64,
+    /// Actual token reserves after the trade
+    pub real_token_reserves: u64,
+}
+
+impl TradeEvent {
+    /// Anchor discriminator of the event
+    pub const DISCRIMINATOR: [u8; 8] = [189, 219, 127, 211, 78, 230, 97, 238];
+
+    /// Get the mint pubkey
+    pub fn mint(&self) -> Pubkey {
+        Pubkey::new_from_array(self.mint_bytes)
+    }
+
+    /// Get the trader pubkey
+    pub fn user(&self) -> Pubkey {
+        Pubkey::new_from_array(self.user_bytes)
+    }
+}
+
+/// Represents the event emitted when a bonding curve completes
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct CompleteEvent {
+    /// User whose trade completed the curve (stored as a byte array)
+    pub user_bytes: [u8; 32],
+    /// Mint of the token (stored as a byte array)
+    pub mint_bytes: [u8; 32],
+    /// Completed bonding curve (stored as a byte array)
+    pub bonding_curve_bytes: [u8; 32],
+    /// Unix timestamp of the completion
+    pub timestamp: i64,
+}
+
+impl CompleteEvent {
+    /// Anchor discriminator of the event
+    pub const DISCRIMINATOR: [u8; 8] = [95, 114, 97, 156, 212, 46, 152, 8];
+
+    /// Get the user pubkey
+    pub fn user(&self) -> Pubkey {
+        Pubkey::new_from_array(self.user_bytes)
+    }
+
+    /// Get the mint pubkey
+    pub fn mint(&self) -> Pubkey {
+        Pubkey::new_from_array(self.mint_bytes)
+    }
+
+    /// Get the bonding curve pubkey
+    pub fn bonding_curve(&self) -> Pubkey {
+        Pubkey::new_from_array(self.bonding_curve_bytes)
+    }
+}
+
+/// Represents the event emitted when the global parameters are updated
+#[derive(Debug, Clone, PartialEq, Eq, BorshSerialize, BorshDeserialize)]
+pub struct SetParamsEvent {
+    /// Account that receives fees (stored as a byte array)
+    pub fee_recipient_bytes: [u8; 32],
+    /// Initial virtual token reserves of new bonding curves
+    pub initial_virtual_token_reserves: u64,
+    /// Initial virtual SOL reserves of new bonding curves
+    pub initial_virtual_sol_reserves: u64,
+    /// Initial actual token reserves of new bonding curves
+    pub initial_real_token_reserves: u64,
+    /// Total supply of new tokens
+    pub token_total_supply: u64,
+    /// Fee in basis points (1/100th of a percent)
+    pub fee_basis_points: u64,
+}
+
+impl SetParamsEvent {
+    /// Anchor discriminator of the event
+    pub const DISCRIMINATOR: [u8; 8] = [223, 195, 159, 246, 62, 48, 143, 131];
+
+    /// Get the fee recipient pubkey
+    pub fn fee_recipient(&self) -> Pubkey {
+        Pubkey::new_from_array(self.fee_recipient_bytes)
+    }
+}
+
+/// Kind of an event emitted by the Pump.fun program
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum EventKind {
+    /// A token was created
+    Create,
+    /// Tokens were bought or sold
+    Trade,
+    /// A bonding curve completed
+    Complete,
+    /// The global parameters were updated
+    SetParams,
+}
+
+/// Any event emitted by the Pump.fun program
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum PumpFunEvent {
+    /// A token was created
+    Create(CreateEvent),
+    /// Tokens were bought or sold
+    Trade(TradeEvent),
+    /// A bonding curve completed
+    Complete(CompleteEvent),
+    /// The global parameters were updated
+    SetParams(SetParamsEvent),
+}
+
+impl PumpFunEvent {
+    /// Gets the kind of the event
+    pub fn kind(&self) -> EventKind {
+        match self {
+            Self::Create(_) => EventKind::Create,
+            Self::Trade(_) => EventKind::Trade,
+            Self::Complete(_) => EventKind::Complete,
+            Self::SetParams(_) => EventKind::SetParams,
+        }
+    }
+
+    /// Gets the mint the event relates to, or None for global events
+    pub fn mint(&self) -> Option<Pubkey> {
+        match self {
+            Self::Create(event) => Some(event.mint()),
+            Self::Trade(event) => Some(event.mint()),
+            Self::Complete(event) => Some(event.mint()),
+            Self::SetParams(_) => None,
+        }
+    }
+
+    /// Decodes an event from its raw bytes
+    ///
+    /// # Arguments
+    /// * `data` - Event discriminator followed by the Borsh serialized event
+    ///
+    /// # Returns
+    /// The decoded event, or None if the discriminator is unknown or the data is malformed
+    pub fn decode(data: &[u8]) -> Option<Self> {
+        if data.len() < 8 {
+            return None;
+        }
+
+        let (discriminator, mut payload) = data.split_at(8);
+        match discriminator {
+            d if d == CreateEvent::DISCRIMINATOR => CreateEvent::deserialize(&mut payload)
+                .ok()
+                .map(Self::Create),
+            d if d == TradeEvent::DISCRIMINATOR => {
+                TradeEvent::deserialize(&mut payload).ok().map(Self::Trade)
+            }
+            d if d == CompleteEvent::DISCRIMINATOR => CompleteEvent::deserialize(&mut payload)
+                .ok()
+                .map(Self::Complete),
+            d if d == SetParamsEvent::DISCRIMINATOR => SetParamsEvent::deserialize(&mut payload)
+                .ok()
+                .map(Self::SetParams),
+            _ => None,
+        }
+    }
+
+    /// Decodes an event from a single "Program data:" log line
+    ///
+    /// # Arguments
+    /// * `log` - Program log line
+    ///
+    /// # Returns
+    /// The decoded event, or None if the line does not carry a Pump.fun event
+    pub fn from_log(log: &str) -> Option<Self> {
+        let encoded = log.strip_prefix(PROGRAM_DATA)?;
+        let data = STANDARD.decode(encoded).ok()?;
+        Self::decode(&data)
+    }
+
+    /// Decodes all events from a transaction's program logs
+    ///
+    /// Every "Program data:" line is decoded regardless of the program that logged it. Use
+    /// `from_logs_with_config` to only decode the events of one program.
+    ///
+    /// # Arguments
+    /// * `logs` - Program log lines, e.g. from a simulation or confirmed transaction
+    ///
+    /// # Returns
+    /// The decoded events in the order they were emitted
+    pub fn from_logs<S: AsRef<str>>(logs: &[S]) -> Vec<Self> {
+        logs.iter()
+            .filter_map(|log| Self::from_log(log.as_ref()))
+            .collect()
+    }
+
+    /// Decodes the events a program emitted from a transaction's program logs
+    ///
+    /// The invoke and completion lines of the logs are followed to attribute each "Program data:"
+    /// line to the program executing at that point, so events of other deployments or programs
+    /// logging the same layout are skipped.
+    ///
+    /// # Arguments
+    /// * `config` - Configuration of the program whose events are decoded
+    /// * `logs` - Program log lines, e.g. from a simulation or confirmed transaction
+    ///
+    /// # Returns
+    /// The decoded events in the order they were emitted
+    pub fn from_logs_with_config<S: AsRef<str>>(config: &ProgramConfig, logs: &[S]) -> Vec<Self> {
+        let program_id = config.program_id.to_string();
+        let mut invocations: Vec<&str> = Vec::new();
+        let mut events = Vec::new();
+
+        for log in logs {
+            let log = log.as_ref();
+            if let Some(rest) = log.strip_prefix(PROGRAM_LOG) {
+                match rest.split_once(' ') {
+                    Some((program, status)) if status.starts_with("invoke [") => {
+                        invocations.push(program);
+                    }
+                    Some((_, status)) if status == "success" || status.starts_with("failed") => {
+                        invocations.pop();
+                    }
+                    _ => {}
+                }
+            }
+
+            if invocations.last() == Some(&program_id.as_str()) {
+                events.extend(Self::from_log(log));
+            }
+        }
+
+        events
+    }
+
+    /// Decodes an event from the data of a self-CPI event instruction
+    ///
+    /// # Arguments
+    /// * `data` - Instruction data: the event instruction tag followed by the event bytes
+    ///
+    /// # Returns
+    /// The decoded event, or None if the data does not carry a Pump.fun event
+    pub fn from_cpi_data(data: &[u8]) -> Option<Self> {
+        let event = data.strip_prefix(&EVENT_IX_TAG)?;
+        Self::decode(event)
+    }
+
+    /// Decodes an event from a self-CPI event instruction
+    ///
+    /// # Arguments
+    /// * `instruction` - Instruction invoked by the program on itself
+    ///
+    /// # Returns
+    /// The decoded event, or None if the instruction is not a Pump.fun event instruction
+    pub fn from_instruction(instruction: &Instruction) -> Option<Self> {
+        Self::from_instruction_with_config(&ProgramConfig::default(), instruction)
+    }
+
+    /// Decodes an event from a self-CPI event instruction of a custom program deployment
+    ///
+    /// # Arguments
+    /// * `config` - Configuration of the program that emitted the event
+    /// * `instruction` - Instruction invoked by the program on itself
+    ///
+    /// # Returns
+    /// The decoded event, or None if the instruction is not an event instruction of the program
+    pub fn from_instruction_with_config(
+        config: &ProgramConfig,
+        instruction: &Instruction,
+    ) -> Option<Self> {
+        let authority = instruction.accounts.first()?;
+        if instruction.program_id != config.program_id || authority.pubkey != config.event_authority
+        {
+            return None;
+        }
+
+        Self::from_cpi_data(&instruction.data)
+    }
+
+    /// Decodes an event from a compiled inner instruction of a transaction
+    ///
+    /// # Arguments
+    /// * `account_keys` - Account keys of the transaction, including loaded addresses
+    /// * `instruction` - Compiled inner instruction
+    ///
+    /// # Returns
+    /// The decoded event, or None if the instruction is not a Pump.fun event instruction
+    pub fn from_compiled_instruction(
+        account_keys: &[Pubkey],
+        instruction: &CompiledInstruction,
+    ) -> Option<Self> {
+        Self::from_compiled_instruction_with_config(
+            &ProgramConfig::default(),
+            account_keys,
+            instruction,
+        )
+    }
+
+    /// Decodes an event from a compiled inner instruction of a custom program deployment
+    ///
+    /// # Arguments
+    /// * `config` - Configuration of the program that emitted the event
+    /// * `account_keys` - Account keys of the transaction, including loaded addresses
+    /// * `instruction` - Compiled inner instruction
+    ///
+    /// # Returns
+    /// The decoded event, or None if the instruction is not an event instruction of the program
+    pub fn from_compiled_instruction_with_config(
+        config: &ProgramConfig,
+        account_keys: &[Pubkey],
+        instruction: &CompiledInstruction,
+    ) -> Option<Self> {
+        let program_id = account_keys.get(instruction.program_id_index as usize)?;
+        let authority = account_keys.get(*instruction.accounts.first()? as usize)?;
+        if *program_id != config.program_id || *authority != config.event_authority {
+            return None;
+        }
+
+        Self::from_cpi_data(&instruction.data)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::constants::accounts::{EVENT_AUTHORITY, PUMPFUN};
+    use anchor_client::solana_sdk::instruction::AccountMeta;
+
+    fn encode_log(discriminator: [u8; 8], event: &impl BorshSerialize) -> String {
+        let mut data = discriminator.to_vec();
+        data.extend(borsh::to_vec(event).unwrap());
+        format!("{}{}", PROGRAM_DATA, STANDARD.encode(data))
+    }
+
+    fn get_trade_event() -> TradeEvent {
+        TradeEvent {
+            mint_bytes: Pubkey::new_unique().to_bytes(),
+            sol_amount: 1_000_000,
+            token_amount: 35_000_000_000,
+            is_buy: true,
+            user_bytes: Pubkey::new_unique().to_bytes(),
+            timestamp: 1_700_000_000,
+            virtual_sol_reserves: 30_001_000_000,
+            virtual_token_reserves: 1_072_965_000_000_000,
+            real_sol_reserves: 1_000_000,
+            real_token_reserves: 792_065_000_000_000,
+        }
+    }
+
+    #[test]
+    fn test_decode_events_from_logs() {
+        let trade = get_trade_event();
+        let create = CreateEvent {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            uri: "https://example.com".to_string(),
+            mint_bytes: trade.mint_bytes,
+            bonding_curve_bytes: Pubkey::new_unique().to_bytes(),
+            user_bytes: trade.user_bytes,
+        };
+
+        let logs = vec![
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]".to_string(),
+            "Program log: Instruction: Create".to_string(),
+            encode_log(CreateEvent::DISCRIMINATOR, &create),
+            encode_log(TradeEvent::DISCRIMINATOR, &trade),
+            "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P success".to_string(),
+        ];
+
+        let events = PumpFunEvent::from_logs(&logs);
+        assert_eq!(
+            events,
+            vec![
+                PumpFunEvent::Create(create.clone()),
+                PumpFunEvent::Trade(trade.clone())
+            ]
+        );
+        assert_eq!(create.mint(), trade.mint());
+    }
+
+    #[test]
+    fn test_decode_rejects_unknown_data() {
+        let trade = get_trade_event();
+
+        // Unknown discriminator
+        assert!(PumpFunEvent::from_log(&encode_log([0; 8], &trade)).is_none());
+
+        // Truncated payload
+        let mut data = TradeEvent::DISCRIMINATOR.to_vec();
+        data.extend(&borsh::to_vec(&trade).unwrap()[..16]);
+        assert!(PumpFunEvent::decode(&data).is_none());
+
+        // Not a data log
+        assert!(PumpFunEvent::from_log("Program log: Instruction: Buy").is_none());
+    }
+
+    #[test]
+    fn test_decode_complete_and_set_params_events() {
+        let complete = CompleteEvent {
+            user_bytes: Pubkey::new_unique().to_bytes(),
+            mint_bytes: Pubkey::new_unique().to_bytes(),
+            bonding_curve_bytes: Pubkey::new_unique().to_bytes(),
+            timestamp: 1_700_000_000,
+        };
+        let set_params = SetParamsEvent {
+            fee_recipient_bytes: Pubkey::new_unique().to_bytes(),
+            initial_virtual_token_reserves: 1_073_000_000_000_000,
+            initial_virtual_sol_reserves: 30_000_000_000,
+            initial_real_token_reserves: 793_100_000_000_000,
+            token_total_supply: 1_000_000_000_000_000,
+            fee_basis_points: 100,
+        };
+
+        let logs = [
+            encode_log(CompleteEvent::DISCRIMINATOR, &complete),
+            encode_log(SetParamsEvent::DISCRIMINATOR, &set_params),
+        ];
+        assert_eq!(
+            PumpFunEvent::from_logs(&logs),
+            vec![
+                PumpFunEvent::Complete(complete),
+                PumpFunEvent::SetParams(set_params)
+            ]
+        );
+    }
+
+    #[test]
+    fn test_decode_cpi_events() {
+        let trade = get_trade_event();
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend(TradeEvent::DISCRIMINATOR);
+        data.extend(borsh::to_vec(&trade).unwrap());
+
+        let instruction = Instruction::new_with_bytes(
+            PUMPFUN,
+            &data,
+            vec![AccountMeta::new_readonly(EVENT_AUTHORITY, true)],
+        );
+        assert_eq!(
+            PumpFunEvent::from_instruction(&instruction),
+            Some(PumpFunEvent::Trade(trade.clone()))
+        );
+
+        let account_keys = [Pubkey::new_unique(), PUMPFUN, EVENT_AUTHORITY];
+        let compiled = CompiledInstruction::new_from_raw_parts(1, data.clone(), vec![2]);
+        assert_eq!(
+            PumpFunEvent::from_compiled_instruction(&account_keys, &compiled),
+            Some(PumpFunEvent::Trade(trade))
+        );
+
+        // Not signed by the event authority
+        let compiled = CompiledInstruction::new_from_raw_parts(1, data.clone(), vec![0]);
+        assert!(PumpFunEvent::from_compiled_instruction(&account_keys, &compiled).is_none());
+
+        // Missing event instruction tag
+        assert!(PumpFunEvent::from_cpi_data(&data[8..]).is_none());
+    }
+
+    #[test]
+    fn test_decode_events_with_config() {
+        let config = ProgramConfig::new(Pubkey::new_unique());
+        let trade = get_trade_event();
+        let mut data = EVENT_IX_TAG.to_vec();
+        data.extend(TradeEvent::DISCRIMINATOR);
+        data.extend(borsh::to_vec(&trade).unwrap());
+
+        let instruction = Instruction::new_with_bytes(
+            config.program_id,
+            &data,
+            vec![AccountMeta::new_readonly(config.event_authority, true)],
+        );
+        assert_eq!(
+            PumpFunEvent::from_instruction_with_config(&config, &instruction),
+            Some(PumpFunEvent::Trade(trade.clone()))
+        );
+        assert!(PumpFunEvent::from_instruction(&instruction).is_none());
+
+        let account_keys = [
+            Pubkey::new_unique(),
+            config.program_id,
+            config.event_authority,
+        ];
+        let compiled = CompiledInstruction::new_from_raw_parts(1, data, vec![2]);
+        assert_eq!(
+            PumpFunEvent::from_compiled_instruction_with_config(&config, &account_keys, &compiled),
+            Some(PumpFunEvent::Trade(trade.clone()))
+        );
+        assert!(PumpFunEvent::from_compiled_instruction(&account_keys, &compiled).is_none());
+
+        // Only events logged while the configured program executes are decoded
+        let event_log = encode_log(TradeEvent::DISCRIMINATOR, &trade);
+        let logs = [
+            format!("Program {} invoke [1]", config.program_id),
+            event_log.clone(),
+            format!("Program {} invoke [2]", PUMPFUN),
+            event_log.clone(),
+            format!("Program {} success", PUMPFUN),
+            format!(
+                "Program {} consumed 30000 of 200000 compute units",
+                config.program_id
+            ),
+            format!("Program {} success", config.program_id),
+            event_log,
+        ];
+        assert_eq!(
+            PumpFunEvent::from_logs_with_config(&config, &logs),
+            vec![PumpFunEvent::Trade(trade.clone())]
+        );
+        assert_eq!(
+            PumpFunEvent::from_logs_with_config(&ProgramConfig::default(), &logs),
+            vec![PumpFunEvent::Trade(trade)]
+        );
+        assert_eq!(PumpFunEvent::from_logs(&logs).len(), 3);
+    }
+}
@@ -0,0 +1,183 @@
+//! Priority fee strategies for Pump.fun transactions.
+//!
+//! This module provides the `FeeStrategy` enum accepted by the trading methods of `PumpFun`, and a
+//! `PriorityFeeEstimator` that derives the compute unit price from the recent prioritization fees
+//! paid for the accounts a transaction writes to.
+//!
+//! # Strategies
+//!
+//! - `Fixed`: Uses the given `PriorityFee` as-is.
+//! - `Percentile`: Uses a percentile of the recent prioritization fees.
+//! - `Estimator`: Uses a configured `PriorityFeeEstimator` with minimum and maximum caps.
+
+use crate::{error, PriorityFee};
+use anchor_client::{
+    solana_client::nonblocking::rpc_client::RpcClient, solana_sdk::pubkey::Pubkey,
+};
+use serde::{Deserialize, Serialize};
+
+/// Estimates compute unit prices from recent prioritization fees
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct PriorityFeeEstimator {
+    /// Percentile of the recent prioritization fees to use, from 0 to 100
+    pub percentile: u8,
+    /// Minimum price in micro-lamports per compute unit
+    pub min_price: Option<u64>,
+    /// Maximum price in micro-lamports per compute unit
+    pub max_price: Option<u64>,
+}
+
+impl PriorityFeeEstimator {
+    /// Creates a new estimator for the given percentile without caps
+    ///
+    /// # Arguments
+    /// * `percentile` - Percentile of the recent prioritization fees to use, from 0 to 100
+    pub fn new(percentile: u8) -> Self {
+        Self {
+            percentile,
+            min_price: None,
+            max_price: None,
+        }
+    }
+
+    /// Estimates the compute unit price for a transaction writing to the given accounts
+    ///
+    /// # Arguments
+    /// * `rpc` - RPC client used to query `getRecentPrioritizationFees`
+    /// * `accounts` - Writable accounts touched by the transaction
+    ///
+    /// # Returns
+    /// The estimated price in micro-lamports per compute unit, or a ClientError if the request fails
+    pub async fn estimate(
+        &self,
+        rpc: &RpcClient,
+        accounts: &[Pubkey],
+    ) -> Result<u64, error::ClientError> {
+        let fees: Vec<u64> = rpc
+            .get_recent_prioritization_fees(accounts)
+            .await
+            .map_err(error::ClientError::from)?
+            .into_iter()
+            .map(|fee| fee.prioritization_fee)
+            .collect();
+
+        Ok(self.price_from_fees(&fees))
+    }
+
+    /// Calculates the price from a set of recent prioritization fees
+    ///
+    /// Uses the nearest-rank percentile and applies the configured caps. An empty set of fees
+    /// yields the minimum price, or zero if none is configured.
+    ///
+    /// # Arguments
+    /// * `fees` - Recent prioritization fees in micro-lamports per compute unit
+    ///
+    /// # Returns
+    /// The price in micro-lamports per compute unit
+    pub fn price_from_fees(&self, fees: &[u64]) -> u64 {
+        let mut sorted = fees.to_vec();
+        sorted.sort_unstable();
+
+        let price = if sorted.is_empty() {
+            0
+        } else {
+            let percentile = self.percentile.min(100) as usize;
+            let rank = (percentile * sorted.len()).div_ceil(100);
+            sorted[rank.saturating_sub(1)]
+        };
+
+        let price = self.min_price.map_or(price, |min| price.max(min));
+        self.max_price.map_or(price, |max| price.min(max))
+    }
+}
+
+/// Strategy for choosing the priority fee of a transaction
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum FeeStrategy {
+    /// Use the given priority fee as-is
+    Fixed(PriorityFee),
+    /// Use a percentile of the recent prioritization fees for the transaction's accounts
+    Percentile {
+        /// Maximum compute units that can be consumed by the transaction
+        limit: Option<u32>,
+        /// Percentile of the recent prioritization fees to use, from 0 to 100
+        percentile: u8,
+    },
+    /// Use the price provided by a configured estimator
+    Estimator {
+        /// Maximum compute units that can be consumed by the transaction
+        limit: Option<u32>,
+        /// Estimator used to derive the compute unit price
+        estimator: PriorityFeeEstimator,
+    },
+}
+
+impl FeeStrategy {
+    /// Resolves the strategy into a concrete priority fee
+    ///
+    /// # Arguments
+    /// * `rpc` - RPC client used by estimating strategies
+    /// * `accounts` - Writable accounts touched by the transaction
+    ///
+    /// # Returns
+    /// The priority fee to apply, or a ClientError if the estimate fails
+    pub async fn resolve(
+        &self,
+        rpc: &RpcClient,
+        accounts: &[Pubkey],
+    ) -> Result<PriorityFee, error::ClientError> {
+        let (limit, estimator) = match *self {
+            Self::Fixed(fee) => return Ok(fee),
+            Self::Percentile { limit, percentile } => {
+                (limit, PriorityFeeEstimator::new(percentile))
+            }
+            Self::Estimator { limit, estimator } => (limit, estimator),
+        };
+
+        Ok(PriorityFee {
+            limit,
+            price: Some(estimator.estimate(rpc, accounts).await?),
+        })
+    }
+}
+
+impl From<PriorityFee> for FeeStrategy {
+    fn from(fee: PriorityFee) -> Self {
+        Self::Fixed(fee)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_price_from_fees() {
+        let fees = [500, 100, 0, 400, 200, 300, 0, 0, 600, 700];
+
+        assert_eq!(PriorityFeeEstimator::new(0).price_from_fees(&fees), 0);
+        assert_eq!(PriorityFeeEstimator::new(50).price_from_fees(&fees), 200);
+        assert_eq!(PriorityFeeEstimator::new(75).price_from_fees(&fees), 500);
+        assert_eq!(PriorityFeeEstimator::new(100).price_from_fees(&fees), 700);
+        assert_eq!(PriorityFeeEstimator::new(255).price_from_fees(&fees), 700);
+        assert_eq!(PriorityFeeEstimator::new(50).price_from_fees(&[]), 0);
+    }
+
+    #[test]
+    fn test_price_from_fees_caps() {
+        let fees = [1_000, 2_000, 3_000];
+        let estimator = PriorityFeeEstimator {
+            percentile: 100,
+            min_price: Some(1_500),
+            max_price: Some(2_500),
+        };
+        assert_eq!(estimator.price_from_fees(&fees), 2_500);
+        assert_eq!(estimator.price_from_fees(&[]), 1_500);
+
+        let estimator = PriorityFeeEstimator {
+            percentile: 0,
+            ..estimator
+        };
+        assert_eq!(estimator.price_from_fees(&fees), 1_500);
+    }
+}
@@ -9,16 +9,17 @@
 //! - `create`: Instruction to create a new token with an associated bonding curve.
 //! - `buy`: Instruction to buy tokens from a bonding curve by providing SOL.
 //! - `sell`: Instruction to sell tokens back to the bonding curve in exchange for SOL.
+//! - `initialize`: Instruction to initialize the program's global state.
+//! - `set_params`: Instruction to update the global parameters of the program.
+//! - `withdraw`: Instruction to withdraw the liquidity of a completed bonding curve.
 
-use crate::{constants, PumpFun};
+use crate::{config::ProgramConfig, constants};
 use anchor_client::anchor_lang::InstructionData;
 use anchor_spl::associated_token::get_associated_token_address;
 use pumpfun_cpi as cpi;
 use solana_sdk::{
     instruction::{AccountMeta, Instruction},
     pubkey::Pubkey,
-    signature::Keypair,
-    signer::Signer,
 };
 
 /// Creates an instruction to create a new token with bonding curve
@@ -27,36 +28,56 @@ use solana_sdk::{
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will pay for account creation and transaction fees
-/// * `mint` - Keypair for the new token mint account that will be created
+/// * `payer` - Public key of the account that will pay for account creation and transaction fees
+/// * `mint` - Public key of the new token mint account that will be created
 /// * `args` - Create instruction data containing token name, symbol and metadata URI
 ///
 /// # Returns
 ///
 /// Returns a Solana instruction that when executed will create the token and its accounts
-pub fn create(payer: &Keypair, mint: &Keypair, args: cpi::instruction::Create) -> Instruction {
-    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(&mint.pubkey()).unwrap();
+pub fn create(payer: &Pubkey, mint: &Pubkey, args: cpi::instruction::Create) -> Instruction {
+    create_with_config(&ProgramConfig::default(), payer, mint, args)
+}
+
+/// Creates the `create` instruction for a program deployed under a custom configuration
+///
+/// See `create` for details.
+///
+/// # Arguments
+///
+/// * `config` - Program configuration providing the program ID, event authority and metadata program
+/// * `payer` - Public key of the account that will pay for account creation and transaction fees
+/// * `mint` - Public key of the new token mint account that will be created
+/// * `args` - Create instruction data containing token name, symbol and metadata URI
+///
+/// # Returns
+///
+/// Returns a Solana instruction for the configured program
+pub fn create_with_config(
+    config: &ProgramConfig,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    args: cpi::instruction::Create,
+) -> Instruction {
+    let bonding_curve: Pubkey = config.bonding_curve_pda(mint).unwrap();
     Instruction::new_with_bytes(
-        constants::accounts::PUMPFUN,
+        config.program_id,
         &args.data(),
         vec![
-            AccountMeta::new(mint.pubkey(), true),
-            AccountMeta::new(PumpFun::get_mint_authority_pda(), false),
+            AccountMeta::new(*mint, true),
+            AccountMeta::new(config.mint_authority_pda(), false),
             AccountMeta::new(bonding_curve, false),
-            AccountMeta::new(
-                get_associated_token_address(&bonding_curve, &mint.pubkey()),
-                false,
-            ),
-            AccountMeta::new_readonly(PumpFun::get_global_pda(), false),
-            AccountMeta::new_readonly(constants::accounts::MPL_TOKEN_METADATA, false),
-            AccountMeta::new(PumpFun::get_metadata_pda(&mint.pubkey()), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
+            AccountMeta::new_readonly(config.global_pda(), false),
+            AccountMeta::new_readonly(config.metadata_program, false),
+            AccountMeta::new(config.metadata_pda(mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::ASSOCIATED_TOKEN_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::RENT, false),
-            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
-            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+            AccountMeta::new_readonly(config.event_authority, false),
+            AccountMeta::new_readonly(config.program_id, false),
         ],
     )
 }
@@ -69,7 +90,7 @@ pub fn create(payer: &Keypair, mint: &Keypair, args: cpi::instruction::Create) -
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that will provide the SOL to buy tokens
+/// * `payer` - Public key of the account that will provide the SOL to buy tokens
 /// * `mint` - Public key of the token mint to buy
 /// * `fee_recipient` - Public key of the account that will receive the transaction fee
 /// * `args` - Buy instruction data containing the SOL amount and maximum acceptable token price
@@ -78,28 +99,53 @@ pub fn create(payer: &Keypair, mint: &Keypair, args: cpi::instruction::Create) -
 ///
 /// Returns a Solana instruction that when executed will buy tokens from the bonding curve
 pub fn buy(
-    payer: &Keypair,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    args: cpi::instruction::Buy,
+) -> Instruction {
+    buy_with_config(&ProgramConfig::default(), payer, mint, fee_recipient, args)
+}
+
+/// Creates the `buy` instruction for a program deployed under a custom configuration
+///
+/// See `buy` for details.
+///
+/// # Arguments
+///
+/// * `config` - Program configuration providing the program ID, event authority and metadata program
+/// * `payer` - Public key of the account that will provide the SOL to buy tokens
+/// * `mint` - Public key of the token mint to buy
+/// * `fee_recipient` - Public key of the account that will receive the transaction fee
+/// * `args` - Buy instruction data containing the SOL amount and maximum acceptable token price
+///
+/// # Returns
+///
+/// Returns a Solana instruction for the configured program
+pub fn buy_with_config(
+    config: &ProgramConfig,
+    payer: &Pubkey,
     mint: &Pubkey,
     fee_recipient: &Pubkey,
     args: cpi::instruction::Buy,
 ) -> Instruction {
-    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(mint).unwrap();
+    let bonding_curve: Pubkey = config.bonding_curve_pda(mint).unwrap();
     Instruction::new_with_bytes(
-        constants::accounts::PUMPFUN,
+        config.program_id,
         &args.data(),
         vec![
-            AccountMeta::new_readonly(PumpFun::get_global_pda(), false),
+            AccountMeta::new_readonly(config.global_pda(), false),
             AccountMeta::new(*fee_recipient, false),
             AccountMeta::new_readonly(*mint, false),
             AccountMeta::new(bonding_curve, false),
             AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-            AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::RENT, false),
-            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
-            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+            AccountMeta::new_readonly(config.event_authority, false),
+            AccountMeta::new_readonly(config.program_id, false),
         ],
     )
 }
@@ -112,7 +158,7 @@ pub fn buy(
 ///
 /// # Arguments
 ///
-/// * `payer` - Keypair that owns the tokens to sell
+/// * `payer` - Public key of the account that owns the tokens to sell
 /// * `mint` - Public key of the token mint to sell
 /// * `fee_recipient` - Public key of the account that will receive the transaction fee
 /// * `args` - Sell instruction data containing token amount and minimum acceptable SOL output
@@ -121,28 +167,337 @@ pub fn buy(
 ///
 /// Returns a Solana instruction that when executed will sell tokens to the bonding curve
 pub fn sell(
-    payer: &Keypair,
+    payer: &Pubkey,
+    mint: &Pubkey,
+    fee_recipient: &Pubkey,
+    args: cpi::instruction::Sell,
+) -> Instruction {
+    sell_with_config(&ProgramConfig::default(), payer, mint, fee_recipient, args)
+}
+
+/// Creates the `sell` instruction for a program deployed under a custom configuration
+///
+/// See `sell` for details.
+///
+/// # Arguments
+///
+/// * `config` - Program configuration providing the program ID, event authority and metadata program
+/// * `payer` - Public key of the account that owns the tokens to sell
+/// * `mint` - Public key of the token mint to sell
+/// * `fee_recipient` - Public key of the account that will receive the transaction fee
+/// * `args` - Sell instruction data containing token amount and minimum acceptable SOL output
+///
+/// # Returns
+///
+/// Returns a Solana instruction for the configured program
+pub fn sell_with_config(
+    config: &ProgramConfig,
+    payer: &Pubkey,
     mint: &Pubkey,
     fee_recipient: &Pubkey,
     args: cpi::instruction::Sell,
 ) -> Instruction {
-    let bonding_curve: Pubkey = PumpFun::get_bonding_curve_pda(mint).unwrap();
+    let bonding_curve: Pubkey = config.bonding_curve_pda(mint).unwrap();
     Instruction::new_with_bytes(
-        constants::accounts::PUMPFUN,
+        config.program_id,
         &args.data(),
         vec![
-            AccountMeta::new_readonly(PumpFun::get_global_pda(), false),
+            AccountMeta::new_readonly(config.global_pda(), false),
             AccountMeta::new(*fee_recipient, false),
             AccountMeta::new_readonly(*mint, false),
             AccountMeta::new(bonding_curve, false),
             AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
-            AccountMeta::new(get_associated_token_address(&payer.pubkey(), mint), false),
-            AccountMeta::new(payer.pubkey(), true),
+            AccountMeta::new(get_associated_token_address(payer, mint), false),
+            AccountMeta::new(*payer, true),
             AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::ASSOCIATED_TOKEN_PROGRAM, false),
             AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
-            AccountMeta::new_readonly(constants::accounts::EVENT_AUTHORITY, false),
-            AccountMeta::new_readonly(constants::accounts::PUMPFUN, false),
+            AccountMeta::new_readonly(config.event_authority, false),
+            AccountMeta::new_readonly(config.program_id, false),
+        ],
+    )
+}
+
+/// Creates an instruction to initialize the program's global state
+///
+/// Creates the global account of a newly deployed program. The signer becomes the program's
+/// authority.
+///
+/// # Arguments
+///
+/// * `payer` - Public key of the account that will pay for the global account and become its authority
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will initialize the program
+pub fn initialize(payer: &Pubkey) -> Instruction {
+    initialize_with_config(&ProgramConfig::default(), payer)
+}
+
+/// Creates the `initialize` instruction for a program deployed under a custom configuration
+///
+/// See `initialize` for details.
+///
+/// # Arguments
+///
+/// * `config` - Program configuration providing the program ID, event authority and metadata program
+/// * `payer` - Public key of the account that will pay for the global account and become its authority
+///
+/// # Returns
+///
+/// Returns a Solana instruction for the configured program
+pub fn initialize_with_config(config: &ProgramConfig, payer: &Pubkey) -> Instruction {
+    Instruction::new_with_bytes(
+        config.program_id,
+        &cpi::instruction::Initialize {}.data(),
+        vec![
+            AccountMeta::new(config.global_pda(), false),
+            AccountMeta::new(*payer, true),
+            AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
+        ],
+    )
+}
+
+/// Creates an instruction to update the global parameters of the program
+///
+/// Sets the fee recipient, the initial reserves of new bonding curves, the token supply and the
+/// fee. Only the program's authority can update the parameters.
+///
+/// # Arguments
+///
+/// * `authority` - Public key of the program's authority
+/// * `args` - Set params instruction data containing the new parameters
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will update the global parameters
+pub fn set_params(authority: &Pubkey, args: cpi::instruction::SetParams) -> Instruction {
+    set_params_with_config(&ProgramConfig::default(), authority, args)
+}
+
+/// Creates the `set_params` instruction for a program deployed under a custom configuration
+///
+/// See `set_params` for details.
+///
+/// # Arguments
+///
+/// * `config` - Program configuration providing the program ID, event authority and metadata program
+/// * `authority` - Public key of the program's authority
+/// * `args` - Set params instruction data containing the new parameters
+///
+/// # Returns
+///
+/// Returns a Solana instruction for the configured program
+pub fn set_params_with_config(
+    config: &ProgramConfig,
+    authority: &Pubkey,
+    args: cpi::instruction::SetParams,
+) -> Instruction {
+    Instruction::new_with_bytes(
+        config.program_id,
+        &args.data(),
+        vec![
+            AccountMeta::new(config.global_pda(), false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(config.event_authority, false),
+            AccountMeta::new_readonly(config.program_id, false),
+        ],
+    )
+}
+
+/// Creates an instruction to withdraw the liquidity of a completed bonding curve
+///
+/// Moves the SOL and remaining tokens of a completed bonding curve to the withdraw authority so
+/// they can be migrated. The tokens are sent to the authority's associated token account, which
+/// must already exist.
+///
+/// # Arguments
+///
+/// * `authority` - Public key of the program's withdraw authority
+/// * `mint` - Public key of the token mint whose bonding curve is withdrawn
+///
+/// # Returns
+///
+/// Returns a Solana instruction that when executed will withdraw the bonding curve's liquidity
+pub fn withdraw(authority: &Pubkey, mint: &Pubkey) -> Instruction {
+    withdraw_with_config(&ProgramConfig::default(), authority, mint)
+}
+
+/// Creates the `withdraw` instruction for a program deployed under a custom configuration
+///
+/// See `withdraw` for details.
+///
+/// # Arguments
+///
+/// * `config` - Program configuration providing the program ID, event authority and metadata program
+/// * `authority` - Public key of the program's withdraw authority
+/// * `mint` - Public key of the token mint whose bonding curve is withdrawn
+///
+/// # Returns
+///
+/// Returns a Solana instruction for the configured program
+pub fn withdraw_with_config(
+    config: &ProgramConfig,
+    authority: &Pubkey,
+    mint: &Pubkey,
+) -> Instruction {
+    let bonding_curve: Pubkey = config.bonding_curve_pda(mint).unwrap();
+    Instruction::new_with_bytes(
+        config.program_id,
+        &cpi::instruction::Withdraw {}.data(),
+        vec![
+            AccountMeta::new_readonly(config.global_pda(), false),
+            AccountMeta::new(config.last_withdraw_pda(), false),
+            AccountMeta::new_readonly(*mint, false),
+            AccountMeta::new(bonding_curve, false),
+            AccountMeta::new(get_associated_token_address(&bonding_curve, mint), false),
+            AccountMeta::new(get_associated_token_address(authority, mint), false),
+            AccountMeta::new(*authority, true),
+            AccountMeta::new_readonly(constants::accounts::SYSTEM_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::TOKEN_PROGRAM, false),
+            AccountMeta::new_readonly(constants::accounts::RENT, false),
+            AccountMeta::new_readonly(config.event_authority, false),
+            AccountMeta::new_readonly(config.program_id, false),
         ],
     )
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::PumpFun;
+    use serde_json::Value;
+    use std::str::FromStr;
+
+    const IDL: &str = include_str!("../../../pumpfun-cpi/idl.json");
+
+    /// Asserts that an instruction's accounts match the IDL definition of the named instruction
+    ///
+    /// Checks the number of accounts, their writable and signer flags, and the accounts with a
+    /// fixed address.
+    fn assert_matches_idl(name: &str, instruction: &Instruction) {
+        let idl: Value = serde_json::from_str(IDL).unwrap();
+        let definition = idl["instructions"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .find(|definition| definition["name"] == name)
+            .unwrap();
+        let accounts = definition["accounts"].as_array().unwrap();
+
+        assert_eq!(instruction.program_id, constants::accounts::PUMPFUN);
+        assert_eq!(instruction.accounts.len(), accounts.len(), "{}", name);
+        for (meta, account) in instruction.accounts.iter().zip(accounts) {
+            let account_name = account["name"].as_str().unwrap();
+            assert_eq!(
+                meta.is_writable,
+                account["writable"].as_bool().unwrap_or(false),
+                "{}: {} writable",
+                name,
+                account_name
+            );
+            assert_eq!(
+                meta.is_signer,
+                account["signer"].as_bool().unwrap_or(false),
+                "{}: {} signer",
+                name,
+                account_name
+            );
+            if let Some(address) = account["address"].as_str() {
+                assert_eq!(
+                    meta.pubkey,
+                    Pubkey::from_str(address).unwrap(),
+                    "{}: {} address",
+                    name,
+                    account_name
+                );
+            }
+        }
+
+        let discriminator: Vec<u8> = definition["discriminator"]
+            .as_array()
+            .unwrap()
+            .iter()
+            .map(|byte| byte.as_u64().unwrap() as u8)
+            .collect();
+        assert_eq!(instruction.data[..8], discriminator[..], "{}", name);
+    }
+
+    #[test]
+    fn test_initialize() {
+        let payer = Pubkey::new_unique();
+        let instruction = initialize(&payer);
+        assert_matches_idl("initialize", &instruction);
+        assert_eq!(instruction.accounts[0].pubkey, PumpFun::get_global_pda());
+        assert_eq!(instruction.accounts[1].pubkey, payer);
+    }
+
+    #[test]
+    fn test_set_params() {
+        let authority = Pubkey::new_unique();
+        let fee_recipient = Pubkey::new_unique();
+        let instruction = set_params(
+            &authority,
+            cpi::instruction::SetParams {
+                _fee_recipient: fee_recipient,
+                _initial_virtual_token_reserves: 1_073_000_000_000_000,
+                _initial_virtual_sol_reserves: 30_000_000_000,
+                _initial_real_token_reserves: 793_100_000_000_000,
+                _token_total_supply: 1_000_000_000_000_000,
+                _fee_basis_points: 100,
+            },
+        );
+        assert_matches_idl("setParams", &instruction);
+        assert_eq!(instruction.accounts[0].pubkey, PumpFun::get_global_pda());
+        assert_eq!(instruction.accounts[1].pubkey, authority);
+        assert_eq!(instruction.data[8..40], fee_recipient.to_bytes());
+        assert_eq!(instruction.data.len(), 8 + 32 + 5 * 8);
+    }
+
+    #[test]
+    fn test_withdraw() {
+        let authority = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let bonding_curve = PumpFun::get_bonding_curve_pda(&mint).unwrap();
+        let instruction = withdraw(&authority, &mint);
+        assert_matches_idl("withdraw", &instruction);
+
+        let accounts: Vec<Pubkey> = instruction.accounts.iter().map(|a| a.pubkey).collect();
+        assert_eq!(
+            accounts[..7],
+            [
+                PumpFun::get_global_pda(),
+                PumpFun::get_last_withdraw_pda(),
+                mint,
+                bonding_curve,
+                get_associated_token_address(&bonding_curve, &mint),
+                get_associated_token_address(&authority, &mint),
+                authority,
+            ]
+        );
+    }
+
+    #[test]
+    fn test_with_config() {
+        let config = ProgramConfig::new(Pubkey::new_unique());
+        let authority = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let bonding_curve = config.bonding_curve_pda(&mint).unwrap();
+
+        let instruction = withdraw_with_config(&config, &authority, &mint);
+        assert_eq!(instruction.program_id, config.program_id);
+        assert_eq!(instruction.data, withdraw(&authority, &mint).data);
+
+        let accounts: Vec<Pubkey> = instruction.accounts.iter().map(|a| a.pubkey).collect();
+        assert_eq!(accounts[0], config.global_pda());
+        assert_eq!(accounts[1], config.last_withdraw_pda());
+        assert_eq!(accounts[3], bonding_curve);
+        assert_eq!(
+            accounts[4],
+            get_associated_token_address(&bonding_curve, &mint)
+        );
+        assert_eq!(accounts[10], config.event_authority);
+        assert_eq!(accounts[11], config.program_id);
+    }
+}
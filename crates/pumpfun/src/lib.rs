@@ -1,35 +1,54 @@
 #![doc = include_str!("../RUSTDOC.md")]
 
 pub mod accounts;
+pub mod builder;
+pub mod config;
 pub mod constants;
+pub mod decoder;
 pub mod error;
+pub mod events;
+pub mod fee;
 pub mod instruction;
+pub mod preflight;
+pub mod quote;
+pub mod ratelimit;
+pub mod reader;
+pub mod subscription;
 pub mod utils;
 
 use anchor_client::{
-    solana_client::nonblocking::rpc_client::RpcClient,
+    solana_client::{
+        nonblocking::rpc_client::RpcClient, rpc_response::RpcSimulateTransactionResult,
+    },
     solana_sdk::{
         commitment_config::CommitmentConfig,
+        hash::Hash,
+        instruction::Instruction,
+        message::{v0, Message, VersionedMessage},
         pubkey::Pubkey,
         signature::{Keypair, Signature},
         signer::Signer,
+        transaction::{Transaction, TransactionError, VersionedTransaction},
     },
-    Client, Cluster, Program,
+    Cluster,
 };
 // use anchor_spl::associated_token::{
 //     get_associated_token_address,
 //     spl_associated_token_account::instruction::create_associated_token_account,
 // };
 use anchor_spl::associated_token::get_associated_token_address;
-use spl_associated_token_account::instruction::create_associated_token_account;
-use borsh::BorshDeserialize;
 pub use pumpfun_cpi as cpi;
 use serde::{Deserialize, Serialize};
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use std::sync::Arc;
+use spl_associated_token_account::instruction::create_associated_token_account;
+use std::{
+    collections::HashMap,
+    ops::{Deref, DerefMut},
+    sync::Arc,
+};
 
 /// Configuration for priority fee compute unit parameters
-#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 pub struct PriorityFee {
     /// Maximum compute units that can be consumed by the transaction
     pub limit: Option<u32>,
@@ -37,61 +56,243 @@ pub struct PriorityFee {
     pub price: Option<u64>,
 }
 
+impl PriorityFee {
+    /// Builds the compute budget instructions for this priority fee configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the compute unit limit and price instructions for the fields that are set
+    pub fn instructions(&self) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+
+        if let Some(limit) = self.limit {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(limit));
+        }
+
+        if let Some(price) = self.price {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(price));
+        }
+
+        instructions
+    }
+}
+
+/// Configuration for sizing the compute unit limit automatically from a simulation
+///
+/// When enabled on the client, transactions without an explicit `PriorityFee.limit` are simulated
+/// first and the limit is set to the consumed compute units plus the configured margin.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct AutoComputeUnitLimit {
+    /// Margin added on top of the simulated compute units in basis points (1 bp = 0.01%)
+    pub margin_basis_points: u64,
+}
+
+impl AutoComputeUnitLimit {
+    /// Maximum compute unit limit a transaction can request
+    pub const MAX_LIMIT: u32 = 1_400_000;
+
+    /// Calculates the compute unit limit for the given simulated consumption
+    ///
+    /// # Arguments
+    ///
+    /// * `units_consumed` - Compute units consumed during simulation
+    ///
+    /// # Returns
+    ///
+    /// Returns the consumed units plus margin, capped at `MAX_LIMIT`
+    pub fn limit_for(&self, units_consumed: u64) -> u32 {
+        let margin = (units_consumed as u128) * (self.margin_basis_points as u128) / 10000;
+        let limit = (units_consumed as u128) + margin;
+        limit.min(Self::MAX_LIMIT as u128) as u32
+    }
+}
+
+impl Default for AutoComputeUnitLimit {
+    fn default() -> Self {
+        Self {
+            margin_basis_points: 1000,
+        }
+    }
+}
+
+/// Outcome of simulating a transaction against the cluster
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SimulationReport {
+    /// Compute units consumed by the transaction, if reported by the node
+    pub units_consumed: Option<u64>,
+    /// Program logs emitted during the simulation
+    pub logs: Vec<String>,
+    /// Pump.fun events decoded from the program logs
+    pub events: Vec<events::PumpFunEvent>,
+    /// Error the transaction failed with, if any
+    pub error: Option<TransactionError>,
+}
+
+impl SimulationReport {
+    /// Whether the simulated transaction succeeded
+    pub fn is_success(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Creates a report from a simulation result, decoding the events of a program deployment
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Configuration of the program whose events are decoded
+    /// * `result` - Simulation result returned by the node
+    ///
+    /// # Returns
+    ///
+    /// Returns the report with the events the program logged
+    pub fn from_result_with_config(
+        config: &config::ProgramConfig,
+        result: RpcSimulateTransactionResult,
+    ) -> Self {
+        let logs: Vec<String> = result.logs.unwrap_or_default();
+        let events = events::PumpFunEvent::from_logs_with_config(config, &logs);
+
+        Self {
+            units_consumed: result.units_consumed,
+            logs,
+            events,
+            error: result.err,
+        }
+    }
+
+    /// Gets the Pump.fun program error the simulated transaction failed with, if any
+    pub fn program_error(&self) -> Option<error::ProgramError> {
+        self.error
+            .as_ref()
+            .and_then(error::ProgramError::from_transaction_error)
+    }
+}
+
+impl From<RpcSimulateTransactionResult> for SimulationReport {
+    fn from(result: RpcSimulateTransactionResult) -> Self {
+        Self::from_result_with_config(&config::ProgramConfig::default(), result)
+    }
+}
+
+/// Bonding curve accounts of several tokens fetched together
+#[derive(Debug, Clone)]
+pub struct BondingCurveAccounts {
+    /// Lowest slot the accounts were read at across all requests
+    pub slot: u64,
+    /// Deserialized bonding curve of each requested mint, or None if the account does not exist
+    pub accounts: HashMap<Pubkey, Option<accounts::BondingCurveAccount>>,
+}
+
 /// Main client for interacting with the Pump.fun program
-pub struct PumpFun {
-    /// RPC client for Solana network requests
-    pub rpc: RpcClient,
-    /// Keypair used to sign transactions
-    pub payer: Arc<Keypair>,
-    /// Anchor client instance
-    pub client: Client<Arc<Keypair>>,
-    /// Anchor program instance
-    pub program: Program<Arc<Keypair>>,
+///
+/// The client is generic over the payer so any [`Signer`] can be used, e.g. `Arc<Keypair>`,
+/// `Arc<dyn Signer>` wrapped in [`anchor_client::DynSigner`], or a remote signer behind an `Arc`.
+pub struct PumpFun<C = Arc<Keypair>> {
+    /// Read-only client for accounts, quotes and subscriptions, also reachable through deref
+    pub reader: reader::PumpFunReader,
+    /// Signer used to sign and pay for transactions
+    pub payer: C,
+    /// Automatic compute unit limit sizing, disabled when None
+    pub auto_compute_unit_limit: Option<AutoComputeUnitLimit>,
+    /// Whether trades check the payer's balances before sending, enabled by default
+    pub preflight_checks: bool,
+    /// Priority fee strategy used by transactions that do not specify one
+    pub default_priority_fee: Option<fee::FeeStrategy>,
 }
 
-impl PumpFun {
+impl<C> Deref for PumpFun<C> {
+    type Target = reader::PumpFunReader;
+
+    fn deref(&self) -> &Self::Target {
+        &self.reader
+    }
+}
+
+impl<C> DerefMut for PumpFun<C> {
+    fn deref_mut(&mut self) -> &mut Self::Target {
+        &mut self.reader
+    }
+}
+
+impl<C: Clone + Deref<Target = impl Signer>> PumpFun<C> {
+    /// Creates a builder for configuring a client paying with the given signer
+    ///
+    /// # Arguments
+    ///
+    /// * `payer` - Signer used to sign and pay for transactions
+    ///
+    /// # Returns
+    ///
+    /// Returns a builder targeting mainnet with default settings
+    pub fn builder(payer: C) -> builder::PumpFunBuilder<C> {
+        builder::PumpFunBuilder::new(payer)
+    }
+
     /// Creates a new PumpFun client instance
     ///
+    /// RPC requests use the cluster's HTTP endpoint and subscriptions its websocket endpoint. Use
+    /// `builder` for separate endpoints, timeouts, headers and trading defaults.
+    ///
     /// # Arguments
     ///
     /// * `cluster` - Solana cluster to connect to (e.g. devnet, mainnet-beta)
-    /// * `payer` - Keypair used to sign and pay for transactions
+    /// * `payer` - Signer used to sign and pay for transactions
     /// * `options` - Optional commitment config for transaction finality
-    /// * `ws` - Whether to use websocket connection instead of HTTP
+    /// * `_ws` - Ignored and kept for compatibility, subscriptions always use the cluster's websocket URL
     ///
     /// # Returns
     ///
     /// Returns a new PumpFun client instance configured with the provided parameters
     pub fn new(
         cluster: Cluster,
-        payer: Arc<Keypair>,
+        payer: C,
         options: Option<CommitmentConfig>,
-        ws: Option<bool>,
+        _ws: Option<bool>,
     ) -> Self {
-        // Create Solana RPC Client with either WS or HTTP endpoint
-        let url = if ws.unwrap_or(false) {
-            cluster.ws_url()
-        } else {
-            cluster.url()
-        };
-        let rpc: RpcClient = RpcClient::new(url.to_string());
+        let commitment = options.unwrap_or_default();
+        let rpc = RpcClient::new_with_commitment(cluster.url().to_string(), commitment);
 
-        // Create Anchor Client with optional commitment config
-        let client: Client<Arc<Keypair>> = if let Some(options) = options {
-            Client::new_with_options(cluster.clone(), Arc::clone(&payer), options)
-        } else {
-            Client::new(cluster.clone(), payer.clone())
-        };
+        Self::builder(payer)
+            .cluster(cluster)
+            .commitment(commitment)
+            .build_with_rpc(rpc)
+    }
 
-        // Create Anchor Program instance for Pump.fun
-        let program: Program<Arc<Keypair>> = client.program(cpi::ID).unwrap();
+    /// Targets a program deployed under a custom configuration, e.g. on localnet or a fork
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Program ID, event authority and metadata program of the deployment
+    ///
+    /// # Returns
+    ///
+    /// Returns the client with PDA derivation and instruction building driven by the configuration
+    pub fn with_program_config(mut self, config: config::ProgramConfig) -> Self {
+        self.reader.config = config;
+        self
+    }
 
-        // Return configured PumpFun client
+    /// Limits the rate of the client's RPC requests and metadata uploads
+    ///
+    /// Requests wait for the limiter before they are sent, and requests rejected as rate limited
+    /// are retried following the retry policy before failing with `ClientError::RateLimitExceeded`.
+    /// Clone the limiter to share it between clients using the same endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `limiter` - Token bucket every request waits for
+    /// * `retry` - Policy for retrying requests rejected as rate limited
+    ///
+    /// # Returns
+    ///
+    /// Returns the client with its RPC connection wrapped in the rate limit
+    pub fn with_rate_limit(
+        self,
+        limiter: ratelimit::RateLimiter,
+        retry: ratelimit::RetryPolicy,
+    ) -> Self {
         Self {
-            rpc,
-            payer,
-            client,
-            program,
+            reader: self.reader.with_rate_limit(limiter, retry),
+            ..self
         }
     }
 
@@ -101,7 +302,7 @@ impl PumpFun {
     ///
     /// * `mint` - Keypair for the new token mint account that will be created
     /// * `metadata` - Token metadata including name, symbol, description and image file
-    /// * `priority_fee` - Optional priority fee configuration for compute units
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
@@ -110,60 +311,72 @@ impl PumpFun {
         &self,
         mint: &Keypair,
         metadata: utils::CreateTokenMetadata,
-        priority_fee: Option<PriorityFee>,
+        priority_fee: Option<fee::FeeStrategy>,
     ) -> Result<Signature, error::ClientError> {
-        // First upload metadata and image to IPFS
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
-            .await
-            .map_err(error::ClientError::UploadMetadataError)?;
-
-        let mut request = self.program.request();
+        let instructions = self
+            .create_instructions(&mint.pubkey(), metadata, priority_fee)
+            .await?;
 
-        // Add priority fee if provided
-        if let Some(fee) = priority_fee {
-            if let Some(limit) = fee.limit {
-                let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
-                request = request.instruction(limit_ix);
-            }
+        self.send_instructions(&instructions, &[mint]).await
+    }
 
-            if let Some(price) = fee.price {
-                let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                request = request.instruction(price_ix);
-            }
-        }
+    /// Builds the instructions for creating a new token without signing or sending them
+    ///
+    /// Uploads the metadata to IPFS and returns the priority fee and create instructions, in order.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the new token mint account, which must also sign the transaction
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn create_instructions(
+        &self,
+        mint: &Pubkey,
+        metadata: utils::CreateTokenMetadata,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // First upload metadata and image to IPFS
+        let ipfs: utils::TokenMetadataResponse = self.upload_metadata(metadata).await?;
 
         // Add create token instruction
-        request = request.instruction(instruction::create(
-            &self.payer,
+        let instructions = vec![instruction::create_with_config(
+            &self.config,
+            &self.payer.pubkey(),
             mint,
             cpi::instruction::Create {
                 _name: ipfs.metadata.name,
                 _symbol: ipfs.metadata.symbol,
                 _uri: ipfs.metadata.image,
             },
-        ));
-
-        // Add signers
-        request = request.signer(&self.payer).signer(mint);
+        )];
 
-        // Send transaction
-        let signature: Signature = request
-            .send()
+        // Prepend priority fee and compute unit limit instructions
+        let fee_accounts = [
+            self.config.global_pda(),
+            self.config
+                .bonding_curve_pda(mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?,
+        ];
+        self.with_compute_budget(instructions, priority_fee, &fee_accounts)
             .await
-            .map_err(error::ClientError::AnchorClientError)?;
-
-        Ok(signature)
     }
 
     /// Creates a new token and immediately buys an initial amount in a single atomic transaction
     ///
+    /// Unless `preflight_checks` is disabled, the payer's balances are checked first and the trade
+    /// fails with `ClientError::InsufficientFunds` if they do not cover it.
+    ///
     /// # Arguments
     ///
     /// * `mint` - Keypair for the new token mint
     /// * `metadata` - Token metadata to upload to IPFS
     /// * `amount_sol` - Amount of SOL to spend on initial buy in lamports
-    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to 500
-    /// * `priority_fee` - Optional priority fee configuration for compute units
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
@@ -174,86 +387,119 @@ impl PumpFun {
         metadata: utils::CreateTokenMetadata,
         amount_sol: u64,
         slippage_basis_points: Option<u64>,
-        priority_fee: Option<PriorityFee>,
+        priority_fee: Option<fee::FeeStrategy>,
     ) -> Result<Signature, error::ClientError> {
-        // Upload metadata to IPFS first
-        let ipfs: utils::TokenMetadataResponse = utils::create_token_metadata(metadata)
-            .await
-            .map_err(error::ClientError::UploadMetadataError)?;
+        let instructions = self
+            .create_and_buy_instructions(
+                &mint.pubkey(),
+                metadata,
+                amount_sol,
+                slippage_basis_points,
+                priority_fee,
+            )
+            .await?;
 
-        // Get accounts and calculate buy amounts
-        let global_account = self.get_global_account().await?;
-        let buy_amount = global_account.get_initial_buy_price(amount_sol);
-        let buy_amount_with_slippage =
-            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
+        if self.preflight_checks {
+            self.check_balances(&mint.pubkey(), &instructions).await?;
+        }
 
-        let mut request = self.program.request();
+        self.send_instructions(&instructions, &[mint]).await
+    }
 
-        // Add priority fee if provided
-        if let Some(fee) = priority_fee {
-            if let Some(limit) = fee.limit {
-                let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
-                request = request.instruction(limit_ix);
-            }
+    /// Builds the instructions for creating a new token and buying an initial amount without
+    /// signing or sending them
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the new token mint, which must also sign the transaction
+    /// * `metadata` - Token metadata to upload to IPFS
+    /// * `amount_sol` - Amount of SOL to spend on initial buy in lamports
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn create_and_buy_instructions(
+        &self,
+        mint: &Pubkey,
+        metadata: utils::CreateTokenMetadata,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Upload metadata to IPFS first
+        let ipfs: utils::TokenMetadataResponse = self.upload_metadata(metadata).await?;
 
-            if let Some(price) = fee.price {
-                let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                request = request.instruction(price_ix);
-            }
-        }
+        // Get accounts and quote the initial buy against the starting curve
+        let global_account = self.get_global_account().await?;
+        let quote = quote::buy_quote(
+            &global_account,
+            &global_account.initial_bonding_curve(),
+            amount_sol,
+            slippage_basis_points.unwrap_or(self.default_slippage_basis_points),
+        )
+        .map_err(error::ClientError::BondingCurveError)?;
 
         // Add create token instruction
-        request = request.instruction(instruction::create(
-            &self.payer,
+        let mut instructions = vec![instruction::create_with_config(
+            &self.config,
+            &self.payer.pubkey(),
             mint,
             cpi::instruction::Create {
                 _name: ipfs.metadata.name,
                 _symbol: ipfs.metadata.symbol,
                 _uri: ipfs.metadata.image,
             },
-        ));
+        )];
 
         // Create Associated Token Account if needed
-        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), &mint.pubkey());
+        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
         if self.rpc.get_account(&ata).await.is_err() {
-            request = request.instruction(create_associated_token_account(
+            instructions.push(create_associated_token_account(
                 &self.payer.pubkey(),
                 &self.payer.pubkey(),
-                &mint.pubkey(),
+                mint,
                 &constants::accounts::TOKEN_PROGRAM,
             ));
         }
 
         // Add buy instruction
-        request = request.instruction(instruction::buy(
-            &self.payer,
-            &mint.pubkey(),
+        instructions.push(instruction::buy_with_config(
+            &self.config,
+            &self.payer.pubkey(),
+            mint,
             &global_account.fee_recipient(),
-            cpi::instruction::Buy {
-                _amount: buy_amount,
-                _max_sol_cost: buy_amount_with_slippage,
-            },
+            quote.buy_args(),
         ));
 
-        // Add signers and send transaction
-        let signature: Signature = request
-            .signer(&self.payer)
-            .signer(mint)
-            .send()
+        // Prepend priority fee and compute unit limit instructions
+        let fee_accounts = [
+            self.config.global_pda(),
+            global_account.fee_recipient(),
+            self.config
+                .bonding_curve_pda(mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?,
+        ];
+        self.with_compute_budget(instructions, priority_fee, &fee_accounts)
             .await
-            .map_err(error::ClientError::AnchorClientError)?;
-
-        Ok(signature)
     }
 
     /// Buys tokens from a bonding curve by spending SOL
     ///
+    /// If the SOL amount buys more tokens than remain in the curve, the buy is clamped to the
+    /// remaining tokens and only the SOL needed for them is spent. Use `get_buy_quote` to detect
+    /// such partial fills beforehand.
+    ///
+    /// Unless `preflight_checks` is disabled, the payer's balances are checked first and the trade
+    /// fails with `ClientError::InsufficientFunds` if they do not cover it.
+    ///
     /// # Arguments
     ///
     /// * `mint` - Public key of the token mint to buy
     /// * `amount_sol` - Amount of SOL to spend in lamports
-    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to 500
-    /// * `priority_fee` - Optional priority fee configuration for compute units
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
@@ -263,36 +509,144 @@ impl PumpFun {
         mint: &Pubkey,
         amount_sol: u64,
         slippage_basis_points: Option<u64>,
-        priority_fee: Option<PriorityFee>,
+        priority_fee: Option<fee::FeeStrategy>,
     ) -> Result<Signature, error::ClientError> {
-        // Get accounts and calculate buy amounts
+        let instructions = self
+            .buy_instructions(mint, amount_sol, slippage_basis_points, priority_fee)
+            .await?;
+
+        if self.preflight_checks {
+            self.check_balances(mint, &instructions).await?;
+        }
+
+        self.send_instructions(&instructions, &[]).await
+    }
+
+    /// Builds the instructions for buying tokens from a bonding curve without signing or sending them
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend in lamports
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn buy_instructions(
+        &self,
+        mint: &Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get accounts and quote the buy
         let global_account = self.get_global_account().await?;
         let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
-        let buy_amount = bonding_curve_account
-            .get_buy_price(amount_sol)
-            .map_err(error::ClientError::BondingCurveError)?;
-        let buy_amount_with_slippage =
-            utils::calculate_with_slippage_buy(amount_sol, slippage_basis_points.unwrap_or(500));
-
-        let mut request = self.program.request();
-
-        // Add priority fee if provided
-        if let Some(fee) = priority_fee {
-            if let Some(limit) = fee.limit {
-                let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
-                request = request.instruction(limit_ix);
-            }
+        let quote = quote::buy_quote(
+            &global_account,
+            &bonding_curve_account,
+            amount_sol,
+            slippage_basis_points.unwrap_or(self.default_slippage_basis_points),
+        )
+        .map_err(error::ClientError::BondingCurveError)?;
 
-            if let Some(price) = fee.price {
-                let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                request = request.instruction(price_ix);
-            }
+        self.build_buy_instructions(
+            mint,
+            &global_account.fee_recipient(),
+            quote.buy_args(),
+            priority_fee,
+        )
+        .await
+    }
+
+    /// Buys an exact amount of tokens from a bonding curve, spending at most a bounded amount of SOL
+    ///
+    /// Unless `preflight_checks` is disabled, the payer's balances are checked first and the trade
+    /// fails with `ClientError::InsufficientFunds` if they do not cover it.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_token` - Amount of tokens to buy in base units
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%) applied to the SOL cost. Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn buy_exact_tokens(
+        &self,
+        mint: &Pubkey,
+        amount_token: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Signature, error::ClientError> {
+        let instructions = self
+            .buy_exact_tokens_instructions(mint, amount_token, slippage_basis_points, priority_fee)
+            .await?;
+
+        if self.preflight_checks {
+            self.check_balances(mint, &instructions).await?;
         }
 
+        self.send_instructions(&instructions, &[]).await
+    }
+
+    /// Builds the instructions for buying an exact amount of tokens without signing or sending them
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_token` - Amount of tokens to buy in base units
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%) applied to the SOL cost. Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn buy_exact_tokens_instructions(
+        &self,
+        mint: &Pubkey,
+        amount_token: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get accounts and quote the SOL cost of the tokens
+        let global_account = self.get_global_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+        let quote = quote::buy_exact_tokens_quote(
+            &global_account,
+            &bonding_curve_account,
+            amount_token,
+            slippage_basis_points.unwrap_or(self.default_slippage_basis_points),
+        )
+        .map_err(error::ClientError::BondingCurveError)?;
+
+        self.build_buy_instructions(
+            mint,
+            &global_account.fee_recipient(),
+            quote.buy_args(),
+            priority_fee,
+        )
+        .await
+    }
+
+    /// Builds the buy instructions for the given arguments, creating the payer's ATA if needed
+    async fn build_buy_instructions(
+        &self,
+        mint: &Pubkey,
+        fee_recipient: &Pubkey,
+        args: cpi::instruction::Buy,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        let mut instructions = Vec::new();
+
         // Create Associated Token Account if needed
         let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
         if self.rpc.get_account(&ata).await.is_err() {
-            request = request.instruction(create_associated_token_account(
+            instructions.push(create_associated_token_account(
                 &self.payer.pubkey(),
                 &self.payer.pubkey(),
                 mint,
@@ -301,36 +655,37 @@ impl PumpFun {
         }
 
         // Add buy instruction
-        request = request.instruction(instruction::buy(
-            &self.payer,
+        instructions.push(instruction::buy_with_config(
+            &self.config,
+            &self.payer.pubkey(),
             mint,
-            &global_account.fee_recipient(),
-            cpi::instruction::Buy {
-                _amount: buy_amount,
-                _max_sol_cost: buy_amount_with_slippage,
-            },
+            fee_recipient,
+            args,
         ));
 
-        // Add signer
-        request = request.signer(&self.payer);
-
-        // Send transaction
-        let signature: Signature = request
-            .send()
+        // Prepend priority fee and compute unit limit instructions
+        let fee_accounts = [
+            self.config.global_pda(),
+            *fee_recipient,
+            self.config
+                .bonding_curve_pda(mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?,
+        ];
+        self.with_compute_budget(instructions, priority_fee, &fee_accounts)
             .await
-            .map_err(error::ClientError::AnchorClientError)?;
-
-        Ok(signature)
     }
 
     /// Sells tokens back to the bonding curve in exchange for SOL
     ///
+    /// Unless `preflight_checks` is disabled, the payer's balances are checked first and the trade
+    /// fails with `ClientError::InsufficientFunds` if they do not cover it.
+    ///
     /// # Arguments
     ///
     /// * `mint` - Public key of the token mint to sell
     /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells entire balance
-    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to 500
-    /// * `priority_fee` - Optional priority fee configuration for compute units
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
@@ -340,173 +695,704 @@ impl PumpFun {
         mint: &Pubkey,
         amount_token: Option<u64>,
         slippage_basis_points: Option<u64>,
-        priority_fee: Option<PriorityFee>,
+        priority_fee: Option<fee::FeeStrategy>,
     ) -> Result<Signature, error::ClientError> {
-        // Get accounts and calculate sell amounts
-        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
-        let balance = self.rpc.get_token_account_balance(&ata).await?;
-        let balance_u64: u64 = balance.amount.parse::<u64>().unwrap();
-        let amount = amount_token.unwrap_or(balance_u64);
-        let global_account = self.get_global_account().await?;
-        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
-        let min_sol_output = bonding_curve_account
-            .get_sell_price(amount, global_account.fee_basis_points)
-            .map_err(error::ClientError::BondingCurveError)?;
-        let min_sol_output = utils::calculate_with_slippage_sell(
-            min_sol_output,
-            slippage_basis_points.unwrap_or(500),
-        );
+        let instructions = self
+            .sell_instructions(mint, amount_token, slippage_basis_points, priority_fee)
+            .await?;
 
-        let mut request = self.program.request();
+        if self.preflight_checks {
+            self.check_balances(mint, &instructions).await?;
+        }
 
-        // Add priority fee if provided
-        if let Some(fee) = priority_fee {
-            if let Some(limit) = fee.limit {
-                let limit_ix = ComputeBudgetInstruction::set_compute_unit_limit(limit);
-                request = request.instruction(limit_ix);
-            }
+        self.send_instructions(&instructions, &[]).await
+    }
 
-            if let Some(price) = fee.price {
-                let price_ix = ComputeBudgetInstruction::set_compute_unit_price(price);
-                request = request.instruction(price_ix);
-            }
-        }
+    /// Builds the instructions for selling tokens back to the bonding curve without signing or
+    /// sending them
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn sell_instructions(
+        &self,
+        mint: &Pubkey,
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        // Get accounts and calculate sell amounts, selling the whole balance by default
+        let amount = match amount_token {
+            Some(amount) => amount,
+            None => self.get_token_balance(mint).await?,
+        };
+        let global_account = self.get_global_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+        let quote = quote::sell_quote(
+            &global_account,
+            &bonding_curve_account,
+            amount,
+            slippage_basis_points.unwrap_or(self.default_slippage_basis_points),
+        )
+        .map_err(error::ClientError::BondingCurveError)?;
 
         // Add sell instruction
-        request = request.instruction(instruction::sell(
-            &self.payer,
+        let instructions = vec![instruction::sell_with_config(
+            &self.config,
+            &self.payer.pubkey(),
             mint,
             &global_account.fee_recipient(),
-            cpi::instruction::Sell {
-                _amount: amount,
-                _min_sol_output: min_sol_output,
-            },
-        ));
+            quote.sell_args(),
+        )];
 
-        // Add signer
-        request = request.signer(&self.payer);
-
-        // Send transaction
-        let signature: Signature = request
-            .send()
+        // Prepend priority fee and compute unit limit instructions
+        let fee_accounts = [
+            self.config.global_pda(),
+            global_account.fee_recipient(),
+            self.config
+                .bonding_curve_pda(mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?,
+        ];
+        self.with_compute_budget(instructions, priority_fee, &fee_accounts)
             .await
-            .map_err(error::ClientError::AnchorClientError)?;
-
-        Ok(signature)
     }
 
-    /// Gets the Program Derived Address (PDA) for the global state account
+    /// Initializes the global state of a newly deployed program, making the payer its authority
     ///
-    /// # Returns
+    /// # Arguments
     ///
-    /// Returns the PDA public key derived from the GLOBAL_SEED
-    pub fn get_global_pda() -> Pubkey {
-        let seeds: &[&[u8]; 1] = &[constants::seeds::GLOBAL_SEED];
-        let program_id: &Pubkey = &cpi::ID;
-        Pubkey::find_program_address(seeds, program_id).0
-    }
-
-    /// Gets the Program Derived Address (PDA) for the mint authority
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key derived from the MINT_AUTHORITY_SEED
-    pub fn get_mint_authority_pda() -> Pubkey {
-        let seeds: &[&[u8]; 1] = &[constants::seeds::MINT_AUTHORITY_SEED];
-        let program_id: &Pubkey = &cpi::ID;
-        Pubkey::find_program_address(seeds, program_id).0
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn initialize(
+        &self,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Signature, error::ClientError> {
+        let instructions = self.initialize_instructions(priority_fee).await?;
+        self.send_instructions(&instructions, &[]).await
     }
 
-    /// Gets the Program Derived Address (PDA) for a token's bonding curve account
+    /// Builds the instructions for initializing the program without signing or sending them
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
-    /// Returns Some(PDA) if derivation succeeds, or None if it fails
-    pub fn get_bonding_curve_pda(mint: &Pubkey) -> Option<Pubkey> {
-        let seeds: &[&[u8]; 2] = &[constants::seeds::BONDING_CURVE_SEED, mint.as_ref()];
-        let program_id: &Pubkey = &cpi::ID;
-        let pda: Option<(Pubkey, u8)> = Pubkey::try_find_program_address(seeds, program_id);
-        pda.map(|pubkey| pubkey.0)
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn initialize_instructions(
+        &self,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        let instructions = vec![instruction::initialize_with_config(
+            &self.config,
+            &self.payer.pubkey(),
+        )];
+
+        // Prepend priority fee and compute unit limit instructions
+        let fee_accounts = [self.config.global_pda()];
+        self.with_compute_budget(instructions, priority_fee, &fee_accounts)
+            .await
     }
 
-    /// Gets the Program Derived Address (PDA) for a token's metadata account
+    /// Updates the global parameters of the program, signed by the payer as the program's authority
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint
+    /// * `args` - New fee recipient, initial reserves, token supply and fee
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
-    /// Returns the PDA public key for the token's metadata account
-    pub fn get_metadata_pda(mint: &Pubkey) -> Pubkey {
-        let seeds: &[&[u8]; 3] = &[
-            constants::seeds::METADATA_SEED,
-            constants::accounts::MPL_TOKEN_METADATA.as_ref(),
-            mint.as_ref(),
-        ];
-        let program_id: &Pubkey = &constants::accounts::MPL_TOKEN_METADATA;
-        Pubkey::find_program_address(seeds, program_id).0
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn set_params(
+        &self,
+        args: cpi::instruction::SetParams,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Signature, error::ClientError> {
+        let instructions = self.set_params_instructions(args, priority_fee).await?;
+        self.send_instructions(&instructions, &[]).await
+    }
+
+    /// Builds the instructions for updating the global parameters without signing or sending them
+    ///
+    /// # Arguments
+    ///
+    /// * `args` - New fee recipient, initial reserves, token supply and fee
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn set_params_instructions(
+        &self,
+        args: cpi::instruction::SetParams,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        let instructions = vec![instruction::set_params_with_config(
+            &self.config,
+            &self.payer.pubkey(),
+            args,
+        )];
+
+        // Prepend priority fee and compute unit limit instructions
+        let fee_accounts = [self.config.global_pda()];
+        self.with_compute_budget(instructions, priority_fee, &fee_accounts)
+            .await
     }
 
-    /// Gets the global state account data containing program-wide configuration
+    /// Withdraws the liquidity of a completed bonding curve, signed by the payer as the program's
+    /// withdraw authority
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint whose bonding curve is withdrawn
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
-    /// Returns the deserialized GlobalAccount if successful, or a ClientError if the operation fails
-    pub async fn get_global_account(&self) -> Result<accounts::GlobalAccount, error::ClientError> {
-        let global: Pubkey = Self::get_global_pda();
+    /// Returns the transaction signature if successful, or a ClientError if the operation fails
+    pub async fn withdraw(
+        &self,
+        mint: &Pubkey,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Signature, error::ClientError> {
+        let instructions = self.withdraw_instructions(mint, priority_fee).await?;
+        self.send_instructions(&instructions, &[]).await
+    }
+
+    /// Builds the instructions for withdrawing the liquidity of a completed bonding curve without
+    /// signing or sending them, creating the payer's ATA if needed
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint whose bonding curve is withdrawn
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the instructions if successful, or a ClientError if the operation fails
+    pub async fn withdraw_instructions(
+        &self,
+        mint: &Pubkey,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        let mut instructions = Vec::new();
+
+        // Create Associated Token Account if needed
+        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
+        if self.rpc.get_account(&ata).await.is_err() {
+            instructions.push(create_associated_token_account(
+                &self.payer.pubkey(),
+                &self.payer.pubkey(),
+                mint,
+                &constants::accounts::TOKEN_PROGRAM,
+            ));
+        }
+
+        // Add withdraw instruction
+        instructions.push(instruction::withdraw_with_config(
+            &self.config,
+            &self.payer.pubkey(),
+            mint,
+        ));
+
+        // Prepend priority fee and compute unit limit instructions
+        let fee_accounts = [
+            self.config.last_withdraw_pda(),
+            self.config
+                .bonding_curve_pda(mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?,
+        ];
+        self.with_compute_budget(instructions, priority_fee, &fee_accounts)
+            .await
+    }
+
+    /// Uploads token metadata to IPFS, applying the client's rate limit
+    async fn upload_metadata(
+        &self,
+        metadata: utils::CreateTokenMetadata,
+    ) -> Result<utils::TokenMetadataResponse, error::ClientError> {
+        let mut retry = 0;
+        loop {
+            if let Some(rate_limit) = &self.rate_limit {
+                rate_limit.limiter.acquire().await;
+            }
+
+            let err = match utils::create_token_metadata(metadata.clone()).await {
+                Ok(response) => return Ok(response),
+                Err(err) => err,
+            };
+
+            let rate_limited = matches!(
+                err.downcast_ref::<error::ClientError>(),
+                Some(error::ClientError::RateLimitExceeded)
+            );
+            if !rate_limited {
+                return Err(error::ClientError::UploadMetadataError(err));
+            }
 
-        let account = self
+            match &self.rate_limit {
+                Some(rate_limit) if retry < rate_limit.retry.max_retries => {
+                    tokio::time::sleep(rate_limit.retry.delay(retry)).await;
+                    retry += 1;
+                }
+                _ => return Err(error::ClientError::RateLimitExceeded),
+            }
+        }
+    }
+
+    /// Signs the instructions with the payer and any additional signers and sends them in a single
+    /// transaction over the client's RPC connection
+    async fn send_instructions(
+        &self,
+        instructions: &[Instruction],
+        additional_signers: &[&dyn Signer],
+    ) -> Result<Signature, error::ClientError> {
+        let recent_blockhash = self
             .rpc
-            .get_account(&global)
+            .get_latest_blockhash()
             .await
-            .map_err(error::ClientError::SolanaClientError)?;
+            .map_err(error::ClientError::from)?;
+
+        let mut signers: Vec<&dyn Signer> = vec![&*self.payer];
+        signers.extend_from_slice(additional_signers);
 
-        accounts::GlobalAccount::try_from_slice(&account.data)
-            .map_err(error::ClientError::BorshError)
+        let mut transaction = self.build_transaction(instructions, recent_blockhash);
+        transaction
+            .try_sign(&signers, recent_blockhash)
+            .map_err(|err| error::ClientError::SolanaClientError(err.into()))?;
+
+        self.rpc
+            .send_and_confirm_transaction(&transaction)
+            .await
+            .map_err(error::ClientError::from)
     }
 
-    /// Gets a token's bonding curve account data containing pricing parameters
+    /// Builds an unsigned legacy transaction paid for by the client's payer
+    ///
+    /// The returned transaction carries empty signatures, so it can be handed to an external
+    /// signer. The signers it requires are listed at the front of the message's account keys.
     ///
     /// # Arguments
     ///
-    /// * `mint` - Public key of the token mint
+    /// * `instructions` - Instructions to include, e.g. from `buy_instructions`
+    /// * `recent_blockhash` - Recent blockhash the transaction will be signed against
+    ///
+    /// # Returns
+    ///
+    /// Returns the unsigned transaction
+    pub fn build_transaction(
+        &self,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Transaction {
+        let message = Message::new_with_blockhash(
+            instructions,
+            Some(&self.payer.pubkey()),
+            &recent_blockhash,
+        );
+        Transaction::new_unsigned(message)
+    }
+
+    /// Builds an unsigned v0 transaction paid for by the client's payer
+    ///
+    /// The returned transaction carries one empty signature per required signer, so it can be
+    /// handed to an external signer.
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions` - Instructions to include, e.g. from `buy_instructions`
+    /// * `recent_blockhash` - Recent blockhash the transaction will be signed against
+    ///
+    /// # Returns
+    ///
+    /// Returns the unsigned transaction if successful, or a ClientError if the message cannot be compiled
+    #[allow(clippy::result_large_err)]
+    pub fn build_versioned_transaction(
+        &self,
+        instructions: &[Instruction],
+        recent_blockhash: Hash,
+    ) -> Result<VersionedTransaction, error::ClientError> {
+        let message =
+            v0::Message::try_compile(&self.payer.pubkey(), instructions, &[], recent_blockhash)
+                .map_err(|_| error::ClientError::InvalidInput("Failed to compile v0 message"))?;
+        let num_signatures = message.header.num_required_signatures as usize;
+
+        Ok(VersionedTransaction {
+            signatures: vec![Signature::default(); num_signatures],
+            message: VersionedMessage::V0(message),
+        })
+    }
+
+    /// Simulates a set of instructions paid for by the client's payer
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions` - Instructions to simulate, e.g. from `buy_instructions`
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation report if the node ran the simulation, or a ClientError if the request fails
+    pub async fn simulate_instructions(
+        &self,
+        instructions: &[Instruction],
+    ) -> Result<SimulationReport, error::ClientError> {
+        let transaction = self.build_transaction(instructions, Hash::default());
+        self.simulate(&transaction).await
+    }
+
+    /// Simulates creating a new token
+    ///
+    /// Note that this uploads the metadata to IPFS, just like `create`.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the new token mint account
+    /// * `metadata` - Token metadata including name, symbol, description and image file
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation report if the node ran the simulation, or a ClientError if the operation fails
+    pub async fn simulate_create(
+        &self,
+        mint: &Pubkey,
+        metadata: utils::CreateTokenMetadata,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<SimulationReport, error::ClientError> {
+        let instructions = self
+            .create_instructions(mint, metadata, priority_fee)
+            .await?;
+        self.simulate_instructions(&instructions).await
+    }
+
+    /// Simulates buying tokens from a bonding curve
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend in lamports
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation report if the node ran the simulation, or a ClientError if the operation fails
+    pub async fn simulate_buy(
+        &self,
+        mint: &Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<SimulationReport, error::ClientError> {
+        let instructions = self
+            .buy_instructions(mint, amount_sol, slippage_basis_points, priority_fee)
+            .await?;
+        self.simulate_instructions(&instructions).await
+    }
+
+    /// Simulates selling tokens back to a bonding curve
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Optional amount of tokens to sell in base units. If None, sells entire balance
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the client's `default_slippage_basis_points`
+    /// * `priority_fee` - Optional priority fee strategy for compute units. Defaults to the client's `default_priority_fee`
     ///
     /// # Returns
     ///
-    /// Returns the deserialized BondingCurveAccount if successful, or a ClientError if the operation fails
-    pub async fn get_bonding_curve_account(
+    /// Returns the simulation report if the node ran the simulation, or a ClientError if the operation fails
+    pub async fn simulate_sell(
         &self,
         mint: &Pubkey,
-    ) -> Result<accounts::BondingCurveAccount, error::ClientError> {
-        let bonding_curve_pda =
-            Self::get_bonding_curve_pda(mint).ok_or(error::ClientError::BondingCurveNotFound)?;
+        amount_token: Option<u64>,
+        slippage_basis_points: Option<u64>,
+        priority_fee: Option<fee::FeeStrategy>,
+    ) -> Result<SimulationReport, error::ClientError> {
+        let instructions = self
+            .sell_instructions(mint, amount_token, slippage_basis_points, priority_fee)
+            .await?;
+        self.simulate_instructions(&instructions).await
+    }
+
+    /// Estimates the compute unit limit for a set of instructions by simulating them
+    ///
+    /// # Arguments
+    ///
+    /// * `instructions` - Instructions to simulate, including any compute budget instructions
+    /// * `config` - Margin to apply on top of the simulated compute units
+    ///
+    /// # Returns
+    ///
+    /// Returns the compute unit limit if the simulation succeeds, or a ClientError if it fails
+    pub async fn estimate_compute_unit_limit(
+        &self,
+        instructions: &[Instruction],
+        config: AutoComputeUnitLimit,
+    ) -> Result<u32, error::ClientError> {
+        let report = self.simulate_instructions(instructions).await?;
+
+        if let Some(program_error) = report.program_error() {
+            return Err(error::ClientError::ProgramError(program_error));
+        }
+
+        if let Some(err) = report.error {
+            return Err(error::ClientError::SimulationError(err.to_string()));
+        }
+
+        let units_consumed = report.units_consumed.ok_or_else(|| {
+            error::ClientError::SimulationError("No compute units reported".to_string())
+        })?;
+
+        Ok(config.limit_for(units_consumed))
+    }
 
-        let account = self
+    /// Prepends the compute budget instructions for a priority fee to a set of instructions
+    ///
+    /// The priority fee strategy, or the client's default, is resolved against the given writable accounts. If automatic
+    /// compute unit limit sizing is enabled and the resolved fee does not set a limit, the
+    /// instructions are simulated with the maximum limit to size it.
+    async fn with_compute_budget(
+        &self,
+        instructions: Vec<Instruction>,
+        priority_fee: Option<fee::FeeStrategy>,
+        fee_accounts: &[Pubkey],
+    ) -> Result<Vec<Instruction>, error::ClientError> {
+        let mut fee = match priority_fee.or(self.default_priority_fee) {
+            Some(strategy) => strategy.resolve(&self.rpc, fee_accounts).await?,
+            None => PriorityFee::default(),
+        };
+
+        if let (None, Some(config)) = (fee.limit, self.auto_compute_unit_limit) {
+            let simulation_fee = PriorityFee {
+                limit: Some(AutoComputeUnitLimit::MAX_LIMIT),
+                ..fee
+            };
+            let mut simulation_instructions = simulation_fee.instructions();
+            simulation_instructions.extend(instructions.iter().cloned());
+
+            fee.limit = Some(
+                self.estimate_compute_unit_limit(&simulation_instructions, config)
+                    .await?,
+            );
+        }
+
+        let mut result = fee.instructions();
+        result.extend(instructions);
+        Ok(result)
+    }
+
+    /// Checks that the payer holds the SOL and tokens needed to execute a set of instructions
+    ///
+    /// See `preflight::BalanceRequirement` for what the requirement covers. Disable the check with
+    /// `preflight_checks` to save the RPC requests on latency-critical paths.
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint traded by the instructions
+    /// * `instructions` - Instructions of the transaction, including compute budget instructions
+    ///
+    /// # Returns
+    ///
+    /// Returns Ok if the balances suffice, ClientError::InsufficientFunds if they do not, or another ClientError if a request fails
+    pub async fn check_balances(
+        &self,
+        mint: &Pubkey,
+        instructions: &[Instruction],
+    ) -> Result<(), error::ClientError> {
+        let requirement =
+            preflight::BalanceRequirement::from_instructions(&self.config, instructions);
+
+        let balance = self
+            .rpc
+            .get_balance(&self.payer.pubkey())
+            .await
+            .map_err(error::ClientError::from)?;
+        if balance < requirement.lamports() {
+            return Err(error::ClientError::InsufficientFunds);
+        }
+
+        if requirement.token_amount > 0
+            && self.get_token_balance(mint).await? < requirement.token_amount
+        {
+            return Err(error::ClientError::InsufficientFunds);
+        }
+
+        Ok(())
+    }
+
+    /// Gets the payer's balance of a token from its associated token account
+    async fn get_token_balance(&self, mint: &Pubkey) -> Result<u64, error::ClientError> {
+        let ata: Pubkey = get_associated_token_address(&self.payer.pubkey(), mint);
+        let balance = self
             .rpc
-            .get_account(&bonding_curve_pda)
+            .get_token_account_balance(&ata)
             .await
-            .map_err(error::ClientError::SolanaClientError)?;
+            .map_err(error::ClientError::from)?;
 
-        accounts::BondingCurveAccount::try_from_slice(&account.data)
-            .map_err(error::ClientError::BorshError)
+        balance
+            .amount
+            .parse()
+            .map_err(|_| error::ClientError::InvalidInput("Invalid token account balance"))
+    }
+}
+
+/// PDA helpers for the Pump.fun program deployed on mainnet
+///
+/// Use the `config` of a client, or a `ProgramConfig`, to derive PDAs for other deployments.
+impl PumpFun {
+    /// Gets the Program Derived Address (PDA) for the global state account
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the GLOBAL_SEED
+    pub fn get_global_pda() -> Pubkey {
+        config::ProgramConfig::default().global_pda()
+    }
+
+    /// Gets the Program Derived Address (PDA) for the mint authority
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the MINT_AUTHORITY_SEED
+    pub fn get_mint_authority_pda() -> Pubkey {
+        config::ProgramConfig::default().mint_authority_pda()
+    }
+
+    /// Gets the Program Derived Address (PDA) for the account tracking the last withdraw
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key derived from the LAST_WITHDRAW_SEED
+    pub fn get_last_withdraw_pda() -> Pubkey {
+        config::ProgramConfig::default().last_withdraw_pda()
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's bonding curve account
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns Some(PDA) if derivation succeeds, or None if it fails
+    pub fn get_bonding_curve_pda(mint: &Pubkey) -> Option<Pubkey> {
+        config::ProgramConfig::default().bonding_curve_pda(mint)
+    }
+
+    /// Gets the Program Derived Address (PDA) for a token's metadata account
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the PDA public key for the token's metadata account
+    pub fn get_metadata_pda(mint: &Pubkey) -> Pubkey {
+        config::ProgramConfig::default().metadata_pda(mint)
     }
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
-    use anchor_client::solana_sdk::signer::keypair::Keypair;
+    use anchor_client::solana_sdk::signer::{keypair::Keypair, null_signer::NullSigner};
 
     #[test]
     fn test_new_client() {
         let payer = Arc::new(Keypair::new());
         let client = PumpFun::new(Cluster::Devnet, payer.clone(), None, None);
         assert_eq!(client.payer.pubkey(), payer.pubkey());
+        assert_eq!(client.cluster, Cluster::Devnet);
+        assert_eq!(client.rpc.url(), Cluster::Devnet.url());
+
+        // RPC requests keep using the HTTP URL when the websocket flag is set
+        let client = PumpFun::new(Cluster::Devnet, payer, None, Some(true));
+        assert_eq!(client.rpc.url(), client.cluster.url());
+        assert_eq!(client.cluster.ws_url(), Cluster::Devnet.ws_url());
+    }
+
+    #[test]
+    fn test_with_program_config() {
+        let payer = Arc::new(Keypair::new());
+        let config = config::ProgramConfig::new(Pubkey::new_unique());
+        let client = PumpFun::new(Cluster::Localnet, payer, None, None).with_program_config(config);
+        assert_eq!(client.config, config);
+    }
+
+    #[test]
+    fn test_with_rate_limit() {
+        let payer = Arc::new(Keypair::new());
+        let client = PumpFun::new(Cluster::Devnet, payer, None, None).with_rate_limit(
+            ratelimit::RateLimiter::new(10, 10),
+            ratelimit::RetryPolicy::default(),
+        );
+        assert!(client.rate_limit.is_some());
+        assert_eq!(client.rpc.url(), Cluster::Devnet.url());
+        assert_eq!(client.rpc.commitment(), CommitmentConfig::default());
+    }
+
+    #[test]
+    fn test_new_client_with_generic_signer() {
+        let pubkey = Pubkey::new_unique();
+        let payer = Arc::new(NullSigner::new(&pubkey));
+        let client = PumpFun::new(Cluster::Devnet, payer, None, None);
+        assert_eq!(client.payer.pubkey(), pubkey);
+
+        let transaction = client.build_transaction(&[], Hash::new_unique());
+        assert_eq!(transaction.message.account_keys, vec![pubkey]);
+    }
+
+    #[test]
+    fn test_auto_compute_unit_limit() {
+        let config = AutoComputeUnitLimit::default();
+        assert_eq!(config.limit_for(50_000), 55_000);
+        assert_eq!(config.limit_for(0), 0);
+
+        let config = AutoComputeUnitLimit {
+            margin_basis_points: 2500,
+        };
+        assert_eq!(config.limit_for(40_001), 50_001);
+        assert_eq!(config.limit_for(u64::MAX), AutoComputeUnitLimit::MAX_LIMIT);
+    }
+
+    #[test]
+    fn test_simulation_report() {
+        let result: RpcSimulateTransactionResult = serde_json::from_value(serde_json::json!({
+            "err": { "InstructionError": [2, { "Custom": 6002 }] },
+            "logs": [
+                "Program 6EF8rrecthR5Dkzon8Nwu78hRvfCKubJ14M5uBEwF6P invoke [1]",
+                "Program log: Instruction: Buy",
+            ],
+            "accounts": null,
+            "unitsConsumed": 31_337,
+            "returnData": null,
+        }))
+        .unwrap();
+
+        let report = SimulationReport::from(result);
+        assert!(!report.is_success());
+        assert_eq!(
+            report.program_error(),
+            Some(error::ProgramError::TooMuchSolRequired)
+        );
+        assert_eq!(report.units_consumed, Some(31_337));
+        assert_eq!(report.logs.len(), 2);
+        assert!(report.events.is_empty());
     }
 
     #[test]
@@ -522,4 +1408,81 @@ mod tests {
         assert!(bonding_curve_pda.is_some());
         assert!(metadata_pda != Pubkey::default());
     }
+
+    #[tokio::test]
+    async fn test_sell_instructions_invalid_balance() {
+        let mut mocks = HashMap::new();
+        mocks.insert(
+            anchor_client::solana_client::rpc_request::RpcRequest::GetTokenAccountBalance,
+            serde_json::json!({
+                "context": { "slot": 1 },
+                "value": {
+                    "amount": "not a number",
+                    "decimals": 6,
+                    "uiAmount": null,
+                    "uiAmountString": "0",
+                },
+            }),
+        );
+
+        let mut client = PumpFun::new(Cluster::Localnet, Arc::new(Keypair::new()), None, None);
+        client.rpc = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+
+        let result = client
+            .sell_instructions(&Pubkey::new_unique(), None, None, None)
+            .await;
+        assert!(matches!(
+            result,
+            Err(error::ClientError::InvalidInput(
+                "Invalid token account balance"
+            ))
+        ));
+    }
+
+    #[test]
+    fn test_priority_fee_instructions() {
+        let fee = PriorityFee {
+            limit: Some(100_000),
+            price: Some(1_000),
+        };
+        assert_eq!(fee.instructions().len(), 2);
+
+        let fee = PriorityFee {
+            limit: None,
+            price: Some(1_000),
+        };
+        assert_eq!(
+            fee.instructions(),
+            vec![ComputeBudgetInstruction::set_compute_unit_price(1_000)]
+        );
+    }
+
+    #[test]
+    fn test_build_unsigned_transactions() {
+        let payer = Arc::new(Keypair::new());
+        let client = PumpFun::new(Cluster::Devnet, payer.clone(), None, None);
+        let mint = Keypair::new();
+        let instructions = vec![instruction::sell(
+            &payer.pubkey(),
+            &mint.pubkey(),
+            &Pubkey::new_unique(),
+            cpi::instruction::Sell {
+                _amount: 1_000,
+                _min_sol_output: 1,
+            },
+        )];
+        let blockhash = Hash::new_unique();
+
+        let transaction = client.build_transaction(&instructions, blockhash);
+        assert_eq!(transaction.message.account_keys[0], payer.pubkey());
+        assert_eq!(transaction.message.recent_blockhash, blockhash);
+        assert!(!transaction.is_signed());
+
+        let transaction = client
+            .build_versioned_transaction(&instructions, blockhash)
+            .unwrap();
+        assert_eq!(transaction.message.static_account_keys()[0], payer.pubkey());
+        assert_eq!(*transaction.message.recent_blockhash(), blockhash);
+        assert_eq!(transaction.signatures, vec![Signature::default()]);
+    }
 }
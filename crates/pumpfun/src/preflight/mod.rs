@@ -0,0 +1,277 @@
+//! Preflight balance checks for Pump.fun transactions.
+//!
+//! This module computes the SOL and tokens the payer needs to execute a set of instructions, so
+//! trades with insufficient balances can be rejected before they are sent. The SOL requirement
+//! covers the maximum SOL cost of buys, which already includes the protocol fee, the priority fee,
+//! the signature fees and the rent of the accounts the instructions create.
+//!
+//! Rent is calculated with the default rent parameters used by all public clusters.
+
+use crate::{config::ProgramConfig, constants, cpi};
+use anchor_client::{
+    anchor_lang::{AnchorDeserialize, Discriminator},
+    solana_sdk::{compute_budget, instruction::Instruction, pubkey::Pubkey, rent::Rent},
+};
+use serde::{Deserialize, Serialize};
+use std::collections::HashSet;
+
+/// Fee charged per transaction signature in lamports
+pub const LAMPORTS_PER_SIGNATURE: u64 = 5000;
+
+/// Size of an SPL token mint account in bytes
+pub const MINT_LEN: usize = 82;
+
+/// Size of an SPL token account in bytes
+pub const TOKEN_ACCOUNT_LEN: usize = 165;
+
+/// Size reserved for a bonding curve account in bytes, covering the layouts of newer program versions
+pub const BONDING_CURVE_LEN: usize = 150;
+
+/// Maximum size of an MPL Token Metadata account in bytes
+pub const METADATA_LEN: usize = 679;
+
+/// Compute unit limit applied to each instruction when the transaction does not set one
+const DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT: u64 = 200_000;
+
+/// Maximum compute unit limit of a transaction
+const MAX_COMPUTE_UNIT_LIMIT: u64 = 1_400_000;
+
+/// Balances the payer needs to execute a set of instructions
+#[derive(Debug, Default, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct BalanceRequirement {
+    /// Maximum SOL spent by buys in lamports, including the protocol fee
+    pub sol_cost: u64,
+    /// Priority fee in lamports
+    pub priority_fee: u64,
+    /// Signature fees in lamports
+    pub signature_fee: u64,
+    /// Rent of the accounts created by the instructions in lamports
+    pub rent: u64,
+    /// Tokens sold in base units
+    pub token_amount: u64,
+}
+
+impl BalanceRequirement {
+    /// Calculates the balances needed to execute a set of instructions
+    ///
+    /// The SOL cost is the `max_sol_cost` of the buy instructions, which bounds the SOL spent
+    /// including the protocol fee. Signature fees are charged for each unique signer of the
+    /// instructions, and at least for the fee payer.
+    ///
+    /// # Arguments
+    /// * `config` - Configuration of the program the instructions invoke
+    /// * `instructions` - Instructions of the transaction, including compute budget instructions
+    ///
+    /// # Returns
+    /// The balance requirement of the instructions
+    pub fn from_instructions(config: &ProgramConfig, instructions: &[Instruction]) -> Self {
+        let rent = Rent::default();
+        let mut requirement = Self {
+            signature_fee: num_signatures(instructions).saturating_mul(LAMPORTS_PER_SIGNATURE),
+            ..Default::default()
+        };
+
+        let mut compute_unit_limit = None;
+        let mut compute_unit_price = 0;
+        let mut num_instructions = 0;
+
+        for instruction in instructions {
+            if instruction.program_id == compute_budget::id() {
+                match instruction.data.split_first() {
+                    Some((2, limit)) => {
+                        compute_unit_limit = limit
+                            .get(..4)
+                            .and_then(|bytes| bytes.try_into().ok())
+                            .map(|bytes| u32::from_le_bytes(bytes) as u64);
+                    }
+                    Some((3, price)) => {
+                        compute_unit_price = price
+                            .get(..8)
+                            .and_then(|bytes| bytes.try_into().ok())
+                            .map_or(0, u64::from_le_bytes);
+                    }
+                    _ => {}
+                }
+                continue;
+            }
+
+            num_instructions += 1;
+
+            if instruction.program_id == constants::accounts::ASSOCIATED_TOKEN_PROGRAM {
+                requirement.rent += rent.minimum_balance(TOKEN_ACCOUNT_LEN);
+            }
+
+            if instruction.program_id != config.program_id || instruction.data.len() < 8 {
+                continue;
+            }
+
+            let (discriminator, mut args) = instruction.data.split_at(8);
+            match discriminator {
+                d if d == cpi::instruction::Create::DISCRIMINATOR => {
+                    requirement.rent +=
+                        [MINT_LEN, BONDING_CURVE_LEN, TOKEN_ACCOUNT_LEN, METADATA_LEN]
+                            .iter()
+                            .map(|len| rent.minimum_balance(*len))
+                            .sum::<u64>();
+                }
+                d if d == cpi::instruction::Buy::DISCRIMINATOR => {
+                    if let Ok(args) = cpi::instruction::Buy::deserialize(&mut args) {
+                        requirement.sol_cost =
+                            requirement.sol_cost.saturating_add(args._max_sol_cost);
+                    }
+                }
+                d if d == cpi::instruction::Sell::DISCRIMINATOR => {
+                    if let Ok(args) = cpi::instruction::Sell::deserialize(&mut args) {
+                        requirement.token_amount =
+                            requirement.token_amount.saturating_add(args._amount);
+                    }
+                }
+                _ => {}
+            }
+        }
+
+        let compute_unit_limit = compute_unit_limit.unwrap_or(
+            (num_instructions * DEFAULT_INSTRUCTION_COMPUTE_UNIT_LIMIT).min(MAX_COMPUTE_UNIT_LIMIT),
+        );
+        let priority_fee =
+            (compute_unit_limit as u128 * compute_unit_price as u128).div_ceil(1_000_000);
+        requirement.priority_fee = u64::try_from(priority_fee).unwrap_or(u64::MAX);
+
+        requirement
+    }
+
+    /// Total lamports the payer needs
+    pub fn lamports(&self) -> u64 {
+        self.sol_cost
+            .saturating_add(self.priority_fee)
+            .saturating_add(self.signature_fee)
+            .saturating_add(self.rent)
+    }
+}
+
+/// Counts the unique signers of a set of instructions, including the fee payer
+fn num_signatures(instructions: &[Instruction]) -> u64 {
+    let signers: HashSet<&Pubkey> = instructions
+        .iter()
+        .flat_map(|instruction| &instruction.accounts)
+        .filter(|meta| meta.is_signer)
+        .map(|meta| &meta.pubkey)
+        .collect();
+
+    signers.len().max(1) as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        accounts::{BondingCurveAccount, GlobalAccount},
+        instruction, quote, PriorityFee,
+    };
+    use anchor_client::solana_sdk::pubkey::Pubkey;
+    use spl_associated_token_account::instruction::create_associated_token_account;
+
+    #[test]
+    fn test_buy_requirement() {
+        let config = ProgramConfig::default();
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+        let global = GlobalAccount::new(
+            1,
+            true,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            1_000_000_000_000_000,
+            100,
+        );
+        let curve = BondingCurveAccount::new(
+            1,
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            1_000_000_000_000_000,
+            false,
+        );
+        let quote = quote::buy_quote(&global, &curve, 1_000_000_000, 500).unwrap();
+
+        let mut instructions = PriorityFee {
+            limit: Some(100_000),
+            price: Some(1_000_001),
+        }
+        .instructions();
+        instructions.push(create_associated_token_account(
+            &payer,
+            &payer,
+            &mint,
+            &constants::accounts::TOKEN_PROGRAM,
+        ));
+        instructions.push(instruction::buy_with_config(
+            &config,
+            &payer,
+            &mint,
+            &global.fee_recipient(),
+            quote.buy_args(),
+        ));
+
+        // The maximum SOL cost of the quote already includes the protocol fee
+        let requirement = BalanceRequirement::from_instructions(&config, &instructions);
+        let rent = Rent::default().minimum_balance(TOKEN_ACCOUNT_LEN);
+        assert_eq!(requirement.sol_cost, quote.max_amount_in);
+        assert_eq!(requirement.priority_fee, 100_001);
+        assert_eq!(requirement.signature_fee, 5_000);
+        assert_eq!(requirement.rent, rent);
+        assert_eq!(requirement.token_amount, 0);
+        assert_eq!(
+            requirement.lamports(),
+            quote.max_amount_in + 100_001 + 5_000 + rent
+        );
+    }
+
+    #[test]
+    fn test_create_and_sell_requirement() {
+        let config = ProgramConfig::default();
+        let payer = Pubkey::new_unique();
+        let mint = Pubkey::new_unique();
+
+        // Without a compute unit limit, each instruction gets the default limit
+        let mut instructions = PriorityFee {
+            limit: None,
+            price: Some(1_000_000),
+        }
+        .instructions();
+        instructions.push(instruction::create(
+            &payer,
+            &mint,
+            cpi::instruction::Create {
+                _name: "Name".to_string(),
+                _symbol: "SYM".to_string(),
+                _uri: "uri".to_string(),
+            },
+        ));
+        // The mint signs the create instruction along with the payer
+        let requirement = BalanceRequirement::from_instructions(&config, &instructions);
+        assert_eq!(requirement.priority_fee, 200_000);
+        assert_eq!(requirement.signature_fee, 10_000);
+        assert!(requirement.rent > Rent::default().minimum_balance(METADATA_LEN));
+
+        let instructions = vec![instruction::sell(
+            &payer,
+            &mint,
+            &Pubkey::new_unique(),
+            cpi::instruction::Sell {
+                _amount: 42,
+                _min_sol_output: 0,
+            },
+        )];
+        let requirement = BalanceRequirement::from_instructions(&config, &instructions);
+        assert_eq!(requirement.signature_fee, 5_000);
+        assert_eq!(requirement.token_amount, 42);
+        assert_eq!(requirement.sol_cost, 0);
+        assert_eq!(requirement.priority_fee, 0);
+        assert_eq!(requirement.rent, 0);
+    }
+}
@@ -0,0 +1,327 @@
+//! Fee-aware quotes for trading against a bonding curve.
+//!
+//! This module produces `Quote`s describing the expected outcome of a buy or sell, including the
+//! protocol fee charged by the program and the slippage bounds passed to the on-chain instructions.
+//!
+//! # Functions
+//!
+//! - `buy_quote`: Quotes spending a SOL budget (including fees) on tokens.
+//! - `buy_exact_tokens_quote`: Quotes buying an exact token amount.
+//! - `sell_quote`: Quotes selling a token amount for SOL.
+
+use crate::{
+    accounts::{BondingCurveAccount, GlobalAccount},
+    cpi,
+    error::CurveError,
+    utils,
+};
+use serde::{Deserialize, Serialize};
+
+/// Direction of a quoted trade
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub enum QuoteSide {
+    /// SOL is spent to buy tokens
+    Buy,
+    /// Tokens are sold for SOL
+    Sell,
+}
+
+/// Expected outcome of a trade against a bonding curve
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+pub struct Quote {
+    /// Direction of the trade
+    pub side: QuoteSide,
+    /// Amount of tokens bought or sold in base units
+    pub token_amount: u64,
+    /// Amount of SOL paid including fees for buys, or received after fees for sells, in lamports
+    pub sol_amount: u64,
+    /// Protocol fee in lamports
+    pub fee: u64,
+    /// Difference between the execution price (excluding fees) and the spot price, in basis points
+    pub price_impact_bps: u64,
+    /// Minimum amount received after slippage: tokens for buys, lamports for sells
+    pub min_amount_out: u64,
+    /// Maximum amount spent after slippage: lamports for buys, tokens for sells
+    pub max_amount_in: u64,
+    /// Whether a buy was clamped to the tokens remaining in the curve, completing it
+    pub partial_fill: bool,
+}
+
+impl Quote {
+    /// Effective price in lamports per token base unit, including fees
+    pub fn effective_price(&self) -> f64 {
+        if self.token_amount == 0 {
+            return 0.0;
+        }
+
+        self.sol_amount as f64 / self.token_amount as f64
+    }
+
+    /// Arguments for the on-chain `buy` instruction of a buy quote
+    pub fn buy_args(&self) -> cpi::instruction::Buy {
+        cpi::instruction::Buy {
+            _amount: self.token_amount,
+            _max_sol_cost: self.max_amount_in,
+        }
+    }
+
+    /// Arguments for the on-chain `sell` instruction of a sell quote
+    pub fn sell_args(&self) -> cpi::instruction::Sell {
+        cpi::instruction::Sell {
+            _amount: self.token_amount,
+            _min_sol_output: self.min_amount_out,
+        }
+    }
+}
+
+/// Quotes spending a SOL budget, including the protocol fee, on tokens
+///
+/// When the budget buys more tokens than remain in the curve, the quote is clamped to the
+/// remaining tokens, its SOL cost is recomputed for them and `partial_fill` is set.
+///
+/// # Arguments
+/// * `global` - Global account providing the fee
+/// * `curve` - Bonding curve to buy from
+/// * `amount_sol` - SOL budget in lamports, including fees
+/// * `slippage_basis_points` - Slippage tolerance applied to the SOL cost
+///
+/// # Returns
+/// * `Ok(Quote)` - Buy quote whose `max_amount_in` is the maximum SOL cost
+/// * `Err(CurveError)` - Error if the curve cannot fill the buy
+pub fn buy_quote(
+    global: &GlobalAccount,
+    curve: &BondingCurveAccount,
+    amount_sol: u64,
+    slippage_basis_points: u64,
+) -> Result<Quote, CurveError> {
+    // Remove the fee from the budget to get the SOL that goes into the curve
+    let net_sol: u128 = (amount_sol as u128) * 10000 / (10000 + global.fee_basis_points as u128);
+    let token_amount = curve.get_buy_price(net_sol as u64)?;
+
+    let quote = buy_exact_tokens_quote(global, curve, token_amount, slippage_basis_points)?;
+    Ok(Quote {
+        partial_fill: token_amount > 0 && token_amount == curve.real_token_reserves,
+        ..quote
+    })
+}
+
+/// Quotes buying an exact amount of tokens
+///
+/// # Arguments
+/// * `global` - Global account providing the fee
+/// * `curve` - Bonding curve to buy from
+/// * `token_amount` - Amount of tokens to buy in base units
+/// * `slippage_basis_points` - Slippage tolerance applied to the SOL cost
+///
+/// # Returns
+/// * `Ok(Quote)` - Buy quote whose `max_amount_in` is the maximum SOL cost
+/// * `Err(CurveError)` - Error if the curve cannot fill the buy
+pub fn buy_exact_tokens_quote(
+    global: &GlobalAccount,
+    curve: &BondingCurveAccount,
+    token_amount: u64,
+    slippage_basis_points: u64,
+) -> Result<Quote, CurveError> {
+    let sol_amount = curve.get_buy_sol_cost(token_amount, global.fee_basis_points)?;
+    let curve_sol = curve.get_buy_sol_cost(token_amount, 0)?;
+
+    Ok(Quote {
+        side: QuoteSide::Buy,
+        token_amount,
+        sol_amount,
+        fee: sol_amount - curve_sol,
+        price_impact_bps: price_impact_bps(curve, token_amount, curve_sol),
+        min_amount_out: token_amount,
+        max_amount_in: utils::calculate_with_slippage_buy(sol_amount, slippage_basis_points),
+        partial_fill: false,
+    })
+}
+
+/// Quotes selling an amount of tokens for SOL
+///
+/// # Arguments
+/// * `global` - Global account providing the fee
+/// * `curve` - Bonding curve to sell to
+/// * `token_amount` - Amount of tokens to sell in base units
+/// * `slippage_basis_points` - Slippage tolerance applied to the SOL output
+///
+/// # Returns
+/// * `Ok(Quote)` - Sell quote whose `min_amount_out` is the minimum SOL output
+/// * `Err(CurveError)` - Error if the curve is complete or the output overflows
+pub fn sell_quote(
+    global: &GlobalAccount,
+    curve: &BondingCurveAccount,
+    token_amount: u64,
+    slippage_basis_points: u64,
+) -> Result<Quote, CurveError> {
+    let sol_amount = curve.get_sell_price(token_amount, global.fee_basis_points)?;
+    let curve_sol = curve.get_sell_price(token_amount, 0)?;
+
+    Ok(Quote {
+        side: QuoteSide::Sell,
+        token_amount,
+        sol_amount,
+        fee: curve_sol - sol_amount,
+        price_impact_bps: price_impact_bps(curve, token_amount, curve_sol),
+        min_amount_out: utils::calculate_with_slippage_sell(sol_amount, slippage_basis_points),
+        max_amount_in: token_amount,
+        partial_fill: false,
+    })
+}
+
+/// Calculates the difference between the execution price and the spot price in basis points
+fn price_impact_bps(curve: &BondingCurveAccount, token_amount: u64, curve_sol: u64) -> u64 {
+    if token_amount == 0 || curve.virtual_token_reserves == 0 || curve.virtual_sol_reserves == 0 {
+        return 0;
+    }
+
+    let spot_price = curve.virtual_sol_reserves as f64 / curve.virtual_token_reserves as f64;
+    let execution_price = curve_sol as f64 / token_amount as f64;
+
+    ((execution_price / spot_price - 1.0).abs() * 10000.0).round() as u64
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use anchor_client::solana_sdk::pubkey::Pubkey;
+
+    fn get_global() -> GlobalAccount {
+        GlobalAccount::new(
+            1,
+            true,
+            Pubkey::new_unique(),
+            Pubkey::new_unique(),
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            1_000_000_000_000_000,
+            100,
+        )
+    }
+
+    fn get_bonding_curve() -> BondingCurveAccount {
+        BondingCurveAccount::new(
+            1,
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            1_000_000_000_000_000,
+            false,
+        )
+    }
+
+    #[test]
+    fn test_buy_quote() {
+        let global = get_global();
+        let curve = get_bonding_curve();
+        let amount_sol = 1_000_000_000;
+
+        let quote = buy_quote(&global, &curve, amount_sol, 500).unwrap();
+        assert_eq!(quote.side, QuoteSide::Buy);
+        assert!(quote.token_amount > 0);
+        assert_eq!(quote.min_amount_out, quote.token_amount);
+
+        // The SOL cost includes the 1% fee and stays within the budget
+        assert!(quote.sol_amount <= amount_sol + 2);
+        assert!(quote.fee > 0);
+        assert!(quote.fee.abs_diff(quote.sol_amount / 101) <= 1);
+
+        // Slippage applies to the actual SOL cost
+        assert_eq!(
+            quote.max_amount_in,
+            utils::calculate_with_slippage_buy(quote.sol_amount, 500)
+        );
+        assert_eq!(quote.buy_args()._max_sol_cost, quote.max_amount_in);
+        assert!(quote.effective_price() > 0.0);
+        assert!(!quote.partial_fill);
+    }
+
+    #[test]
+    fn test_buy_quote_partial_fill() {
+        let global = get_global();
+        let mut curve = get_bonding_curve();
+        curve.real_token_reserves = 1_000_000_000_000;
+
+        // The budget buys far more tokens than remain in the curve
+        let amount_sol = 10_000_000_000;
+        let quote = buy_quote(&global, &curve, amount_sol, 500).unwrap();
+        assert!(quote.partial_fill);
+        assert_eq!(quote.token_amount, curve.real_token_reserves);
+
+        // The SOL cost is recomputed for the remaining tokens only
+        let sol_cost = curve
+            .get_buy_sol_cost(curve.real_token_reserves, global.fee_basis_points)
+            .unwrap();
+        assert_eq!(quote.sol_amount, sol_cost);
+        assert!(quote.sol_amount < amount_sol);
+        assert_eq!(
+            quote.max_amount_in,
+            utils::calculate_with_slippage_buy(sol_cost, 500)
+        );
+    }
+
+    #[test]
+    fn test_buy_exact_tokens_quote() {
+        let global = get_global();
+        let curve = get_bonding_curve();
+
+        let quote = buy_exact_tokens_quote(&global, &curve, 1_000_000_000_000, 100).unwrap();
+        assert_eq!(quote.token_amount, 1_000_000_000_000);
+        assert_eq!(
+            quote.sol_amount,
+            curve
+                .get_buy_sol_cost(1_000_000_000_000, global.fee_basis_points)
+                .unwrap()
+        );
+        assert_eq!(quote.buy_args()._amount, 1_000_000_000_000);
+    }
+
+    #[test]
+    fn test_sell_quote() {
+        let global = get_global();
+        let curve = get_bonding_curve();
+
+        let quote = sell_quote(&global, &curve, 1_000_000_000_000, 500).unwrap();
+        assert_eq!(quote.side, QuoteSide::Sell);
+        assert_eq!(quote.max_amount_in, 1_000_000_000_000);
+        assert!(quote.fee > 0);
+        assert_eq!(
+            quote.min_amount_out,
+            utils::calculate_with_slippage_sell(quote.sol_amount, 500)
+        );
+        assert_eq!(quote.sell_args()._min_sol_output, quote.min_amount_out);
+    }
+
+    #[test]
+    fn test_price_impact() {
+        let global = get_global();
+        let curve = get_bonding_curve();
+
+        let small = buy_quote(&global, &curve, 1_000_000, 0).unwrap();
+        let large = buy_quote(&global, &curve, 10_000_000_000, 0).unwrap();
+        assert!(small.price_impact_bps < large.price_impact_bps);
+        assert!(large.price_impact_bps > 0);
+
+        let empty = buy_quote(&global, &curve, 0, 0).unwrap();
+        assert_eq!(empty.price_impact_bps, 0);
+        assert_eq!(empty.effective_price(), 0.0);
+    }
+
+    #[test]
+    fn test_quote_complete_curve() {
+        let global = get_global();
+        let mut curve = get_bonding_curve();
+        curve.complete = true;
+
+        assert_eq!(
+            buy_quote(&global, &curve, 1_000_000, 500),
+            Err(CurveError::Complete)
+        );
+        assert_eq!(
+            sell_quote(&global, &curve, 1_000_000, 500),
+            Err(CurveError::Complete)
+        );
+    }
+}
@@ -0,0 +1,398 @@
+//! Client-side rate limiting for RPC requests and metadata uploads.
+//!
+//! This module provides the `RateLimiter` token bucket shared by every request a `PumpFun` client
+//! makes, and the `RetryPolicy` used to retry requests the server rejects as rate limited, with
+//! exponential backoff and jitter. Requests still rate limited once the retries run out fail with
+//! `ClientError::RateLimitExceeded`.
+//!
+//! RPC requests are limited by wrapping the client's `RpcClient` in a `RateLimitedSender`. Clients
+//! without a rate limit do not retry, and report rate limited requests as `RateLimitExceeded` right
+//! away.
+
+use anchor_client::solana_client::{
+    client_error::{ClientError as SolanaClientError, ClientErrorKind},
+    nonblocking::rpc_client::RpcClient,
+    rpc_request::{RpcError, RpcRequest},
+    rpc_sender::{RpcSender, RpcTransportStats},
+};
+use rand::Rng;
+use std::{
+    sync::{Arc, Mutex},
+    time::{Duration, Instant},
+};
+
+/// Token bucket limiting the rate of requests
+///
+/// Clones share the same bucket, so a single limiter can throttle several clients using the same
+/// endpoint.
+#[derive(Debug, Clone)]
+pub struct RateLimiter {
+    bucket: Arc<Mutex<TokenBucket>>,
+}
+
+impl RateLimiter {
+    /// Creates a limiter allowing a sustained rate of requests with bursts up to a maximum
+    ///
+    /// # Arguments
+    /// * `requests_per_second` - Sustained number of requests allowed per second, at least 1
+    /// * `burst` - Maximum number of requests allowed at once, at least 1
+    pub fn new(requests_per_second: u32, burst: u32) -> Self {
+        Self {
+            bucket: Arc::new(Mutex::new(TokenBucket::new(
+                requests_per_second.max(1) as f64,
+                burst.max(1) as f64,
+                Instant::now(),
+            ))),
+        }
+    }
+
+    /// Takes a request from the bucket if one is available without waiting
+    ///
+    /// # Returns
+    /// Whether the request may be sent
+    pub fn try_acquire(&self) -> bool {
+        self.bucket.lock().unwrap().try_take(Instant::now()).is_ok()
+    }
+
+    /// Waits until a request is available and takes it from the bucket
+    pub async fn acquire(&self) {
+        loop {
+            let wait = match self.bucket.lock().unwrap().try_take(Instant::now()) {
+                Ok(()) => return,
+                Err(wait) => wait,
+            };
+            tokio::time::sleep(wait).await;
+        }
+    }
+}
+
+/// State of a token bucket, refilled continuously at a fixed rate
+#[derive(Debug)]
+struct TokenBucket {
+    /// Tokens added per second
+    rate: f64,
+    /// Maximum number of tokens held
+    capacity: f64,
+    /// Tokens currently held
+    tokens: f64,
+    /// Time the tokens were last refilled
+    last_refill: Instant,
+}
+
+impl TokenBucket {
+    /// Creates a full bucket
+    fn new(rate: f64, capacity: f64, now: Instant) -> Self {
+        Self {
+            rate,
+            capacity,
+            tokens: capacity,
+            last_refill: now,
+        }
+    }
+
+    /// Takes a token, or returns how long until one is available
+    fn try_take(&mut self, now: Instant) -> Result<(), Duration> {
+        let elapsed = now
+            .saturating_duration_since(self.last_refill)
+            .as_secs_f64();
+        self.tokens = (self.tokens + elapsed * self.rate).min(self.capacity);
+        self.last_refill = now;
+
+        if self.tokens >= 1.0 {
+            self.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - self.tokens) / self.rate))
+        }
+    }
+}
+
+/// Policy for retrying requests rejected as rate limited
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Maximum number of retries after the first attempt
+    pub max_retries: u32,
+    /// Delay before the first retry, doubled for every following retry
+    pub base_delay: Duration,
+    /// Maximum delay between two attempts
+    pub max_delay: Duration,
+}
+
+impl RetryPolicy {
+    /// Policy that never retries
+    pub const NONE: Self = Self {
+        max_retries: 0,
+        base_delay: Duration::ZERO,
+        max_delay: Duration::ZERO,
+    };
+
+    /// Calculates the delay before a retry
+    ///
+    /// The delay grows exponentially with the retry number and is randomized between half and
+    /// all of it, so clients rate limited together do not retry in lockstep.
+    ///
+    /// # Arguments
+    /// * `retry` - Number of the retry, starting at 0
+    ///
+    /// # Returns
+    /// The delay to wait before the retry
+    pub fn delay(&self, retry: u32) -> Duration {
+        let backoff = self
+            .base_delay
+            .saturating_mul(2u32.saturating_pow(retry))
+            .min(self.max_delay);
+        let jitter = rand::thread_rng().gen_range(0.5..=1.0);
+        backoff.mul_f64(jitter)
+    }
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_retries: 3,
+            base_delay: Duration::from_millis(250),
+            max_delay: Duration::from_secs(5),
+        }
+    }
+}
+
+/// Message of the errors returned by `RateLimitedSender` once the retries run out
+const RETRIES_EXHAUSTED: &str = "Rate limit exceeded after retries";
+
+/// Rate limiting applied to the requests of a client
+#[derive(Debug, Clone)]
+pub struct RateLimit {
+    /// Limiter every request waits for before it is sent
+    pub limiter: RateLimiter,
+    /// Policy for retrying requests rejected as rate limited
+    pub retry: RetryPolicy,
+}
+
+/// Checks whether an RPC request failed because the server rate limited it
+///
+/// Detects HTTP 429 responses as well as JSON-RPC errors reporting rate limiting.
+///
+/// # Arguments
+/// * `err` - Error the request failed with
+pub fn is_rate_limited(err: &SolanaClientError) -> bool {
+    match err.kind() {
+        ClientErrorKind::Reqwest(err) => err.status().map(|status| status.as_u16()) == Some(429),
+        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, message, .. }) => {
+            matches!(code, 429 | -32429) || is_rate_limit_message(message)
+        }
+        ClientErrorKind::RpcError(RpcError::RpcRequestError(message))
+        | ClientErrorKind::RpcError(RpcError::ForUser(message))
+        | ClientErrorKind::Custom(message) => is_rate_limit_message(message),
+        _ => false,
+    }
+}
+
+/// Checks whether a request failed because it was still rate limited after all retries
+///
+/// # Arguments
+/// * `err` - Error returned through a `RateLimitedSender`
+pub(crate) fn is_retries_exhausted(err: &SolanaClientError) -> bool {
+    matches!(err.kind(), ClientErrorKind::Custom(message) if message.starts_with(RETRIES_EXHAUSTED))
+}
+
+/// Checks whether an error message reports rate limiting
+fn is_rate_limit_message(message: &str) -> bool {
+    let message = message.to_lowercase();
+    message.contains("too many requests") || message.contains("rate limit")
+}
+
+/// RPC transport applying a rate limit to the requests of another client
+pub struct RateLimitedSender {
+    inner: RpcClient,
+    rate_limit: RateLimit,
+}
+
+impl RateLimitedSender {
+    /// Wraps a client so its requests wait for the limiter and are retried when rate limited
+    ///
+    /// # Arguments
+    /// * `inner` - Client sending the requests
+    /// * `rate_limit` - Limiter and retry policy to apply
+    pub fn new(inner: RpcClient, rate_limit: RateLimit) -> Self {
+        Self { inner, rate_limit }
+    }
+}
+
+#[async_trait::async_trait]
+impl RpcSender for RateLimitedSender {
+    async fn send(
+        &self,
+        request: RpcRequest,
+        params: serde_json::Value,
+    ) -> Result<serde_json::Value, SolanaClientError> {
+        let mut retry = 0;
+        loop {
+            self.rate_limit.limiter.acquire().await;
+
+            match self.inner.send(request, params.clone()).await {
+                Err(err) if is_rate_limited(&err) => {
+                    if retry >= self.rate_limit.retry.max_retries {
+                        let message = format!("{}: {}", RETRIES_EXHAUSTED, err);
+                        return Err(ClientErrorKind::Custom(message).into());
+                    }
+
+                    tokio::time::sleep(self.rate_limit.retry.delay(retry)).await;
+                    retry += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    fn get_transport_stats(&self) -> RpcTransportStats {
+        self.inner.get_transport_stats()
+    }
+
+    fn url(&self) -> String {
+        self.inner.url()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::error::ClientError;
+    use anchor_client::solana_client::rpc_request::RpcResponseErrorData;
+
+    #[test]
+    fn test_token_bucket() {
+        let start = Instant::now();
+        let mut bucket = TokenBucket::new(10.0, 2.0, start);
+
+        // The bucket starts full and allows a burst up to its capacity
+        assert!(bucket.try_take(start).is_ok());
+        assert!(bucket.try_take(start).is_ok());
+        let wait = bucket.try_take(start).unwrap_err();
+        assert_eq!(wait, Duration::from_millis(100));
+
+        // Tokens refill at the configured rate without exceeding the capacity
+        assert!(bucket.try_take(start + Duration::from_millis(100)).is_ok());
+        assert!(bucket.try_take(start + Duration::from_millis(100)).is_err());
+        let later = start + Duration::from_secs(10);
+        assert!(bucket.try_take(later).is_ok());
+        assert!(bucket.try_take(later).is_ok());
+        assert!(bucket.try_take(later).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_shared_limiter() {
+        let limiter = RateLimiter::new(1, 1);
+        let shared = limiter.clone();
+
+        assert!(limiter.try_acquire());
+        assert!(!shared.try_acquire());
+    }
+
+    #[test]
+    fn test_retry_delay() {
+        let policy = RetryPolicy::default();
+
+        for retry in 0..10 {
+            let backoff = (policy.base_delay * 2u32.pow(retry)).min(policy.max_delay);
+            let delay = policy.delay(retry);
+            assert!(delay >= backoff / 2 && delay <= backoff);
+        }
+
+        assert_eq!(RetryPolicy::NONE.delay(3), Duration::ZERO);
+    }
+
+    #[test]
+    fn test_is_rate_limited() {
+        let rpc_error = |code: i64, message: &str| -> SolanaClientError {
+            RpcError::RpcResponseError {
+                code,
+                message: message.to_string(),
+                data: RpcResponseErrorData::Empty,
+            }
+            .into()
+        };
+
+        assert!(is_rate_limited(&rpc_error(429, "Too Many Requests")));
+        assert!(is_rate_limited(&rpc_error(-32000, "Rate limit exceeded")));
+        assert!(!is_rate_limited(&rpc_error(
+            -32005,
+            "Node is behind by 42 slots"
+        )));
+        assert!(!is_rate_limited(&rpc_error(
+            -32002,
+            "Transaction simulation failed"
+        )));
+        assert!(!is_rate_limited(&SolanaClientError::from(
+            ClientErrorKind::Custom("connection refused".to_string())
+        )));
+    }
+
+    /// Transport rejecting every request as rate limited
+    struct RateLimitedTransport;
+
+    #[async_trait::async_trait]
+    impl RpcSender for RateLimitedTransport {
+        async fn send(
+            &self,
+            _request: RpcRequest,
+            _params: serde_json::Value,
+        ) -> Result<serde_json::Value, SolanaClientError> {
+            Err(RpcError::RpcResponseError {
+                code: 429,
+                message: "Too Many Requests".to_string(),
+                data: RpcResponseErrorData::Empty,
+            }
+            .into())
+        }
+
+        fn get_transport_stats(&self) -> RpcTransportStats {
+            RpcTransportStats::default()
+        }
+
+        fn url(&self) -> String {
+            "http://localhost:8899".to_string()
+        }
+    }
+
+    #[tokio::test]
+    async fn test_retries_exhausted() {
+        // Without a rate limit, rate limited requests fail without retries
+        let rpc = RpcClient::new_sender(RateLimitedTransport, Default::default());
+        let err = rpc.get_version().await.unwrap_err();
+        assert!(!is_retries_exhausted(&err));
+        assert!(matches!(
+            ClientError::from(err),
+            ClientError::RateLimitExceeded
+        ));
+
+        // Other RPC errors are not mistaken for rate limiting
+        assert!(matches!(
+            ClientError::from(SolanaClientError::from(RpcError::RpcResponseError {
+                code: -32005,
+                message: "Node is behind by 42 slots".to_string(),
+                data: RpcResponseErrorData::Empty,
+            })),
+            ClientError::SolanaClientError(_)
+        ));
+
+        let rate_limit = RateLimit {
+            limiter: RateLimiter::new(1000, 1000),
+            retry: RetryPolicy {
+                max_retries: 2,
+                base_delay: Duration::ZERO,
+                max_delay: Duration::ZERO,
+            },
+        };
+        let sender = RateLimitedSender::new(
+            RpcClient::new_sender(RateLimitedTransport, Default::default()),
+            rate_limit,
+        );
+        let rpc = RpcClient::new_sender(sender, Default::default());
+        let err = rpc.get_version().await.unwrap_err();
+        assert!(is_retries_exhausted(&err));
+        assert!(matches!(
+            ClientError::from(err),
+            ClientError::RateLimitExceeded
+        ));
+    }
+}
@@ -0,0 +1,511 @@
+//! Read-only client for the Pump.fun program
+//!
+//! `PumpFunReader` fetches accounts, quotes trades, simulates transactions and subscribes to
+//! program events without a signer, so services that only read on-chain state never hold keys.
+//! PDAs are derived with the reader's `config`, and events are decoded by `subscribe_events`.
+//!
+//! Trading clients wrap a reader: `PumpFun` dereferences to its `PumpFunReader`, so every read
+//! method is also available on a trading client.
+
+use crate::{
+    accounts, builder, config, error, quote, ratelimit, subscription, BondingCurveAccounts,
+    SimulationReport,
+};
+use anchor_client::{
+    solana_client::{
+        nonblocking::rpc_client::RpcClient,
+        rpc_client::{RpcClientConfig, SerializableTransaction},
+        rpc_config::{
+            RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSimulateTransactionConfig,
+        },
+        rpc_request::MAX_MULTIPLE_ACCOUNTS,
+    },
+    solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey},
+    Cluster,
+};
+use solana_account_decoder::UiAccountEncoding;
+use std::collections::HashMap;
+
+/// Client for reading the state of the Pump.fun program without a signer
+pub struct PumpFunReader {
+    /// RPC client for Solana network requests
+    pub rpc: RpcClient,
+    /// Cluster the client is connected to
+    pub cluster: Cluster,
+    /// Program ID and accounts used to derive PDAs and build instructions
+    pub config: config::ProgramConfig,
+    /// Websocket connection shared by account subscriptions
+    pub pubsub: subscription::SharedPubsubClient,
+    /// Rate limiting applied to RPC requests and metadata uploads, set with `with_rate_limit`
+    pub rate_limit: Option<ratelimit::RateLimit>,
+    /// Slippage in basis points (1 bp = 0.01%) used by quotes and trades that do not specify one
+    pub default_slippage_basis_points: u64,
+}
+
+impl PumpFunReader {
+    /// Creates a builder for configuring a reader
+    ///
+    /// # Returns
+    ///
+    /// Returns a builder targeting mainnet with default settings
+    pub fn builder() -> builder::PumpFunBuilder<()> {
+        builder::PumpFunBuilder::new(())
+    }
+
+    /// Creates a new reader connected to a cluster
+    ///
+    /// # Arguments
+    ///
+    /// * `cluster` - Solana cluster to connect to (e.g. devnet, mainnet-beta)
+    /// * `options` - Optional commitment config for reads and subscriptions
+    ///
+    /// # Returns
+    ///
+    /// Returns a new reader configured with the provided parameters
+    pub fn new(cluster: Cluster, options: Option<CommitmentConfig>) -> Self {
+        let commitment = options.unwrap_or_default();
+        let rpc = RpcClient::new_with_commitment(cluster.url().to_string(), commitment);
+
+        Self::builder()
+            .cluster(cluster)
+            .commitment(commitment)
+            .build_reader_with_rpc(rpc)
+    }
+
+    /// Targets a program deployed under a custom configuration, e.g. on localnet or a fork
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - Program ID, event authority and metadata program of the deployment
+    ///
+    /// # Returns
+    ///
+    /// Returns the reader with PDA derivation driven by the configuration
+    pub fn with_program_config(mut self, config: config::ProgramConfig) -> Self {
+        self.config = config;
+        self
+    }
+
+    /// Limits the rate of the reader's RPC requests
+    ///
+    /// Requests wait for the limiter before they are sent, and requests rejected as rate limited
+    /// are retried following the retry policy before failing with `ClientError::RateLimitExceeded`.
+    /// Clone the limiter to share it between clients using the same endpoint.
+    ///
+    /// # Arguments
+    ///
+    /// * `limiter` - Token bucket every request waits for
+    /// * `retry` - Policy for retrying requests rejected as rate limited
+    ///
+    /// # Returns
+    ///
+    /// Returns the reader with its RPC connection wrapped in the rate limit
+    pub fn with_rate_limit(
+        self,
+        limiter: ratelimit::RateLimiter,
+        retry: ratelimit::RetryPolicy,
+    ) -> Self {
+        let rate_limit = ratelimit::RateLimit { limiter, retry };
+        let commitment = self.rpc.commitment();
+        let sender = ratelimit::RateLimitedSender::new(self.rpc, rate_limit.clone());
+
+        Self {
+            rpc: RpcClient::new_sender(sender, RpcClientConfig::with_commitment(commitment)),
+            rate_limit: Some(rate_limit),
+            ..self
+        }
+    }
+
+    /// Simulates a transaction without requiring valid signatures
+    ///
+    /// Signature verification is skipped and the blockhash is replaced with a recent one, so
+    /// unsigned transactions from `build_transaction` can be simulated as-is.
+    ///
+    /// # Arguments
+    ///
+    /// * `transaction` - Legacy or versioned transaction to simulate
+    ///
+    /// # Returns
+    ///
+    /// Returns the simulation report if the node ran the simulation, or a ClientError if the request fails
+    pub async fn simulate(
+        &self,
+        transaction: &impl SerializableTransaction,
+    ) -> Result<SimulationReport, error::ClientError> {
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(self.rpc.commitment()),
+            ..Default::default()
+        };
+
+        let response = self
+            .rpc
+            .simulate_transaction_with_config(transaction, config)
+            .await
+            .map_err(error::ClientError::from)?;
+
+        Ok(SimulationReport::from_result_with_config(
+            &self.config,
+            response.value,
+        ))
+    }
+
+    /// Quotes spending an amount of SOL, including fees, on a token
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_sol` - Amount of SOL to spend in lamports, including fees
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the reader's `default_slippage_basis_points`
+    ///
+    /// # Returns
+    ///
+    /// Returns the buy quote if successful, or a ClientError if the operation fails
+    pub async fn get_buy_quote(
+        &self,
+        mint: &Pubkey,
+        amount_sol: u64,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<quote::Quote, error::ClientError> {
+        let global_account = self.get_global_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+
+        quote::buy_quote(
+            &global_account,
+            &bonding_curve_account,
+            amount_sol,
+            slippage_basis_points.unwrap_or(self.default_slippage_basis_points),
+        )
+        .map_err(error::ClientError::BondingCurveError)
+    }
+
+    /// Quotes buying an exact amount of a token
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to buy
+    /// * `amount_token` - Amount of tokens to buy in base units
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the reader's `default_slippage_basis_points`
+    ///
+    /// # Returns
+    ///
+    /// Returns the buy quote if successful, or a ClientError if the operation fails
+    pub async fn get_buy_exact_tokens_quote(
+        &self,
+        mint: &Pubkey,
+        amount_token: u64,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<quote::Quote, error::ClientError> {
+        let global_account = self.get_global_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+
+        quote::buy_exact_tokens_quote(
+            &global_account,
+            &bonding_curve_account,
+            amount_token,
+            slippage_basis_points.unwrap_or(self.default_slippage_basis_points),
+        )
+        .map_err(error::ClientError::BondingCurveError)
+    }
+
+    /// Quotes selling an amount of a token for SOL
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint to sell
+    /// * `amount_token` - Amount of tokens to sell in base units
+    /// * `slippage_basis_points` - Optional maximum acceptable slippage in basis points (1 bp = 0.01%). Defaults to the reader's `default_slippage_basis_points`
+    ///
+    /// # Returns
+    ///
+    /// Returns the sell quote if successful, or a ClientError if the operation fails
+    pub async fn get_sell_quote(
+        &self,
+        mint: &Pubkey,
+        amount_token: u64,
+        slippage_basis_points: Option<u64>,
+    ) -> Result<quote::Quote, error::ClientError> {
+        let global_account = self.get_global_account().await?;
+        let bonding_curve_account = self.get_bonding_curve_account(mint).await?;
+
+        quote::sell_quote(
+            &global_account,
+            &bonding_curve_account,
+            amount_token,
+            slippage_basis_points.unwrap_or(self.default_slippage_basis_points),
+        )
+        .map_err(error::ClientError::BondingCurveError)
+    }
+
+    /// Subscribes to the events emitted by the Pump.fun program over the cluster's websocket
+    ///
+    /// The subscription uses `logsSubscribe` for transactions mentioning the program and
+    /// reconnects and resubscribes automatically when the connection drops.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Filter selecting the mints and kinds of events to yield
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream of decoded events if the initial subscription succeeds, or a ClientError if it fails
+    pub async fn subscribe_events(
+        &self,
+        filter: subscription::EventFilter,
+    ) -> Result<impl futures::Stream<Item = subscription::EventNotification>, error::ClientError>
+    {
+        subscription::subscribe_events(
+            self.cluster.ws_url().to_string(),
+            self.config,
+            self.rpc.commitment(),
+            filter,
+        )
+        .await
+    }
+
+    /// Subscribes to a token's bonding curve account over the client's shared websocket connection
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream yielding the deserialized account every time it changes if successful, or a ClientError if the subscription fails
+    pub async fn subscribe_bonding_curve(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<impl futures::Stream<Item = subscription::CurveUpdate>, error::ClientError> {
+        self.subscribe_bonding_curves(std::slice::from_ref(mint))
+            .await
+    }
+
+    /// Subscribes to the bonding curve accounts of several tokens over the client's shared websocket connection
+    ///
+    /// # Arguments
+    ///
+    /// * `mints` - Public keys of the token mints
+    ///
+    /// # Returns
+    ///
+    /// Returns a stream yielding the deserialized accounts every time one changes if successful, or a ClientError if a subscription fails
+    pub async fn subscribe_bonding_curves(
+        &self,
+        mints: &[Pubkey],
+    ) -> Result<impl futures::Stream<Item = subscription::CurveUpdate>, error::ClientError> {
+        self.pubsub
+            .subscribe_bonding_curves(&self.config, mints, self.rpc.commitment())
+            .await
+    }
+
+    /// Gets the global state account data containing program-wide configuration
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized GlobalAccount if successful, or a ClientError if the operation fails
+    pub async fn get_global_account(&self) -> Result<accounts::GlobalAccount, error::ClientError> {
+        let global: Pubkey = self.config.global_pda();
+
+        let account = self
+            .rpc
+            .get_account(&global)
+            .await
+            .map_err(error::ClientError::from)?;
+
+        accounts::GlobalAccount::try_from_account_data(&account.data)
+    }
+
+    /// Gets a token's bonding curve account data containing pricing parameters
+    ///
+    /// # Arguments
+    ///
+    /// * `mint` - Public key of the token mint
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized BondingCurveAccount if successful, or a ClientError if the operation fails
+    pub async fn get_bonding_curve_account(
+        &self,
+        mint: &Pubkey,
+    ) -> Result<accounts::BondingCurveAccount, error::ClientError> {
+        let bonding_curve_pda = self
+            .config
+            .bonding_curve_pda(mint)
+            .ok_or(error::ClientError::BondingCurveNotFound)?;
+
+        let account = self
+            .rpc
+            .get_account(&bonding_curve_pda)
+            .await
+            .map_err(error::ClientError::from)?;
+
+        accounts::BondingCurveAccount::try_from_account_data(&account.data)
+    }
+
+    /// Gets the bonding curve accounts of several tokens
+    ///
+    /// The bonding curve PDAs are fetched with concurrent `getMultipleAccounts` requests of up
+    /// to 100 accounts each.
+    ///
+    /// # Arguments
+    ///
+    /// * `mints` - Public keys of the token mints
+    ///
+    /// # Returns
+    ///
+    /// Returns the deserialized accounts keyed by mint along with the slot they were read at if successful, or a ClientError if the operation fails
+    pub async fn get_bonding_curve_accounts(
+        &self,
+        mints: &[Pubkey],
+    ) -> Result<BondingCurveAccounts, error::ClientError> {
+        let mut pdas = Vec::with_capacity(mints.len());
+        for mint in mints {
+            pdas.push(
+                self.config
+                    .bonding_curve_pda(mint)
+                    .ok_or(error::ClientError::BondingCurveNotFound)?,
+            );
+        }
+
+        let responses =
+            futures::future::try_join_all(pdas.chunks(MAX_MULTIPLE_ACCOUNTS).map(|chunk| {
+                self.rpc
+                    .get_multiple_accounts_with_commitment(chunk, self.rpc.commitment())
+            }))
+            .await
+            .map_err(error::ClientError::from)?;
+
+        let slot = responses
+            .iter()
+            .map(|response| response.context.slot)
+            .min()
+            .unwrap_or_default();
+
+        let mut accounts = HashMap::with_capacity(mints.len());
+        let fetched = responses.into_iter().flat_map(|response| response.value);
+        for (mint, account) in mints.iter().zip(fetched) {
+            let curve = match account {
+                Some(account) => Some(accounts::BondingCurveAccount::try_from_account_data(
+                    &account.data,
+                )?),
+                None => None,
+            };
+            accounts.insert(*mint, curve);
+        }
+
+        Ok(BondingCurveAccounts { slot, accounts })
+    }
+
+    /// Gets every bonding curve account of the program matching a filter
+    ///
+    /// The accounts are fetched in a single `getProgramAccounts` request, which some RPC
+    /// providers restrict or rate limit more aggressively than other methods.
+    ///
+    /// # Arguments
+    ///
+    /// * `filter` - Conditions the bonding curves must satisfy
+    ///
+    /// # Returns
+    ///
+    /// Returns each matching bonding curve's PDA alongside its deserialized account if successful, or a ClientError if the operation fails
+    pub async fn get_all_bonding_curves(
+        &self,
+        filter: accounts::BondingCurveFilter,
+    ) -> Result<Vec<(Pubkey, accounts::BondingCurveAccount)>, error::ClientError> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filter.rpc_filters()),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                commitment: Some(self.rpc.commitment()),
+                ..Default::default()
+            },
+            ..Default::default()
+        };
+
+        let program_accounts = self
+            .rpc
+            .get_program_accounts_with_config(&self.config.program_id, config)
+            .await
+            .map_err(error::ClientError::from)?;
+
+        let mut curves = Vec::with_capacity(program_accounts.len());
+        for (pubkey, account) in program_accounts {
+            let curve = accounts::BondingCurveAccount::try_from_account_data(&account.data)?;
+            if filter.matches(&curve) {
+                curves.push((pubkey, curve));
+            }
+        }
+
+        Ok(curves)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_new_reader() {
+        let config = config::ProgramConfig::new(Pubkey::new_unique());
+        let reader = PumpFunReader::new(Cluster::Devnet, Some(CommitmentConfig::confirmed()))
+            .with_program_config(config);
+        assert_eq!(reader.rpc.url(), Cluster::Devnet.url());
+        assert_eq!(reader.cluster, Cluster::Devnet);
+        assert_eq!(reader.rpc.commitment(), CommitmentConfig::confirmed());
+        assert_eq!(reader.config, config);
+        assert!(reader.rate_limit.is_none());
+    }
+
+    #[tokio::test]
+    async fn test_get_bonding_curve_accounts() {
+        let curve = accounts::BondingCurveAccount::new(
+            u64::from_le_bytes(accounts::BondingCurveAccount::DISCRIMINATOR),
+            1000,
+            1000,
+            500,
+            42,
+            1000,
+            false,
+        );
+        let data = base64::Engine::encode(
+            &base64::engine::general_purpose::STANDARD,
+            borsh::to_vec(&curve).unwrap(),
+        );
+
+        let mut mocks = HashMap::new();
+        mocks.insert(
+            anchor_client::solana_client::rpc_request::RpcRequest::GetMultipleAccounts,
+            serde_json::json!({
+                "context": { "slot": 77 },
+                "value": [
+                    {
+                        "lamports": 1_000_000,
+                        "data": [data, "base64"],
+                        "owner": crate::cpi::ID.to_string(),
+                        "executable": false,
+                        "rentEpoch": 0,
+                        "space": accounts::BondingCurveAccount::LEN,
+                    },
+                    null,
+                ],
+            }),
+        );
+
+        let mut reader = PumpFunReader::new(Cluster::Localnet, None);
+        reader.rpc = RpcClient::new_mock_with_mocks("succeeds".to_string(), mocks);
+
+        let mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let batch = reader.get_bonding_curve_accounts(&mints).await.unwrap();
+        assert_eq!(batch.slot, 77);
+        assert_eq!(batch.accounts.len(), 2);
+        assert_eq!(
+            batch.accounts[&mints[0]]
+                .as_ref()
+                .unwrap()
+                .real_sol_reserves,
+            42
+        );
+        assert!(batch.accounts[&mints[1]].is_none());
+    }
+}
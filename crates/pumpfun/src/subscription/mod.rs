@@ -0,0 +1,804 @@
+//! Live subscriptions to the Pump.fun program over websockets.
+//!
+//! This module streams decoded Pump.fun events from the `logsSubscribe` websocket method. The
+//! subscription runs in a background task that reconnects and resubscribes whenever the connection
+//! drops, so a stream keeps yielding events until it is dropped.
+//!
+//! Events emitted while the connection is down are not replayed after reconnecting.
+//!
+//! It also streams bonding curve state from the `accountSubscribe` websocket method. Curve
+//! subscriptions share a single connection held by a `SharedPubsubClient`; their streams end when
+//! that connection drops, and subscribing again reconnects.
+//!
+//! # Types
+//!
+//! - `EventFilter`: Selects the events yielded by a subscription.
+//! - `EventNotification`: A decoded event along with the transaction that emitted it.
+//! - `SharedPubsubClient`: A websocket connection shared by many subscriptions.
+//! - `CurveUpdate`: A bonding curve account along with the slot it changed in.
+
+use crate::{
+    accounts::BondingCurveAccount,
+    config::ProgramConfig,
+    error,
+    events::{EventKind, PumpFunEvent},
+};
+use anchor_client::{
+    solana_client::{
+        nonblocking::pubsub_client::{PubsubClient, PubsubClientError},
+        rpc_config::{RpcAccountInfoConfig, RpcTransactionLogsConfig, RpcTransactionLogsFilter},
+        rpc_response::{Response, RpcLogsResponse},
+    },
+    solana_sdk::{commitment_config::CommitmentConfig, pubkey::Pubkey, signature::Signature},
+};
+use futures::{stream, Stream, StreamExt};
+use solana_account_decoder::{UiAccount, UiAccountEncoding};
+use std::{sync::Arc, time::Duration};
+use tokio::sync::{mpsc, oneshot, Mutex};
+
+/// Delay before the first reconnection attempt after a dropped connection
+const RECONNECT_DELAY_MIN: Duration = Duration::from_millis(500);
+
+/// Maximum delay between reconnection attempts
+const RECONNECT_DELAY_MAX: Duration = Duration::from_secs(30);
+
+/// Selects the events yielded by a subscription
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub struct EventFilter {
+    /// Only yield events for these mints, or events for any mint when empty
+    pub mints: Vec<Pubkey>,
+    /// Only yield these kinds of events, or every kind when empty
+    pub kinds: Vec<EventKind>,
+}
+
+impl EventFilter {
+    /// Whether an event passes the filter
+    ///
+    /// # Arguments
+    /// * `event` - Decoded event
+    ///
+    /// # Returns
+    /// True if the event matches both the mints and the kinds of the filter
+    pub fn matches(&self, event: &PumpFunEvent) -> bool {
+        let mint_matches =
+            self.mints.is_empty() || event.mint().is_some_and(|mint| self.mints.contains(&mint));
+        let kind_matches = self.kinds.is_empty() || self.kinds.contains(&event.kind());
+
+        mint_matches && kind_matches
+    }
+}
+
+/// A decoded event along with the transaction that emitted it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct EventNotification {
+    /// Signature of the transaction that emitted the event
+    pub signature: Signature,
+    /// Slot the transaction was processed in
+    pub slot: u64,
+    /// Decoded event
+    pub event: PumpFunEvent,
+}
+
+/// Subscribes to the events emitted by successful Pump.fun transactions
+///
+/// # Arguments
+/// * `ws_url` - Websocket URL of the RPC node
+/// * `config` - Configuration of the Pump.fun program whose transactions and events to watch
+/// * `commitment` - Commitment level of the notifications
+/// * `filter` - Filter applied to the decoded events
+///
+/// # Returns
+/// A stream of the matching events once the first subscription succeeds, or a ClientError if the
+/// initial connection or subscription fails. Later connection failures are retried with backoff.
+pub async fn subscribe_events(
+    ws_url: String,
+    config: ProgramConfig,
+    commitment: CommitmentConfig,
+    filter: EventFilter,
+) -> Result<impl Stream<Item = EventNotification>, error::ClientError> {
+    let (sender, receiver) = mpsc::unbounded_channel();
+    let (ready, subscribed) = oneshot::channel();
+
+    tokio::spawn(run_event_subscription(
+        ws_url, config, commitment, filter, sender, ready,
+    ));
+
+    subscribed
+        .await
+        .map_err(|err| PubsubClientError::ConnectionClosed(err.to_string()))??;
+
+    Ok(receiver_stream(receiver))
+}
+
+/// Keeps an event subscription alive until the receiving stream is dropped
+async fn run_event_subscription(
+    ws_url: String,
+    config: ProgramConfig,
+    commitment: CommitmentConfig,
+    filter: EventFilter,
+    sender: mpsc::UnboundedSender<EventNotification>,
+    ready: oneshot::Sender<Result<(), PubsubClientError>>,
+) {
+    let mut ready = Some(ready);
+    let mut delay = RECONNECT_DELAY_MIN;
+
+    loop {
+        match forward_events(&ws_url, &config, commitment, &filter, &sender, &mut ready).await {
+            // The connection dropped after subscribing, so reconnect quickly
+            Ok(()) => delay = RECONNECT_DELAY_MIN,
+            // Report failures of the initial subscription to the caller instead of retrying
+            Err(err) => {
+                if let Some(ready) = ready.take() {
+                    let _ = ready.send(Err(err));
+                    return;
+                }
+            }
+        }
+
+        tokio::select! {
+            _ = tokio::time::sleep(delay) => {}
+            _ = sender.closed() => return,
+        }
+        delay = (delay * 2).min(RECONNECT_DELAY_MAX);
+    }
+}
+
+/// Connects, subscribes and forwards events until the connection or the receiver closes
+async fn forward_events(
+    ws_url: &str,
+    config: &ProgramConfig,
+    commitment: CommitmentConfig,
+    filter: &EventFilter,
+    sender: &mpsc::UnboundedSender<EventNotification>,
+    ready: &mut Option<oneshot::Sender<Result<(), PubsubClientError>>>,
+) -> Result<(), PubsubClientError> {
+    let client = PubsubClient::new(ws_url).await?;
+    let (mut notifications, _unsubscribe) = client
+        .logs_subscribe(
+            RpcTransactionLogsFilter::Mentions(vec![config.program_id.to_string()]),
+            RpcTransactionLogsConfig {
+                commitment: Some(commitment),
+            },
+        )
+        .await?;
+
+    if let Some(ready) = ready.take() {
+        let _ = ready.send(Ok(()));
+    }
+
+    loop {
+        tokio::select! {
+            notification = notifications.next() => match notification {
+                Some(notification) => {
+                    for event in decode_notification(config, notification, filter) {
+                        if sender.send(event).is_err() {
+                            break;
+                        }
+                    }
+                }
+                None => break,
+            },
+            _ = sender.closed() => break,
+        }
+    }
+
+    drop(notifications);
+    let _ = client.shutdown().await;
+    Ok(())
+}
+
+/// Decodes the matching events of a logs notification, skipping failed transactions
+fn decode_notification(
+    config: &ProgramConfig,
+    notification: Response<RpcLogsResponse>,
+    filter: &EventFilter,
+) -> Vec<EventNotification> {
+    let Response { context, value } = notification;
+    if value.err.is_some() {
+        return Vec::new();
+    }
+
+    let signature: Signature = value.signature.parse().unwrap_or_default();
+    PumpFunEvent::from_logs_with_config(config, &value.logs)
+        .into_iter()
+        .filter(|event| filter.matches(event))
+        .map(|event| EventNotification {
+            signature,
+            slot: context.slot,
+            event,
+        })
+        .collect()
+}
+
+/// A bonding curve account along with the slot it changed in
+#[derive(Debug, Clone)]
+pub struct CurveUpdate {
+    /// Mint of the token traded on the bonding curve
+    pub mint: Pubkey,
+    /// Slot the account changed in
+    pub slot: u64,
+    /// Deserialized bonding curve account
+    pub bonding_curve: BondingCurveAccount,
+}
+
+/// A websocket connection shared by many subscriptions
+///
+/// The connection is opened by the first subscription. If it drops, the streams using it end and
+/// the next subscription opens a new connection.
+pub struct SharedPubsubClient {
+    /// Websocket URL of the RPC node
+    ws_url: String,
+    /// Open connection, if any
+    client: Mutex<Option<Arc<PubsubClient>>>,
+}
+
+impl SharedPubsubClient {
+    /// Creates a shared client for a websocket URL without connecting
+    ///
+    /// # Arguments
+    /// * `ws_url` - Websocket URL of the RPC node
+    pub fn new(ws_url: impl Into<String>) -> Self {
+        Self {
+            ws_url: ws_url.into(),
+            client: Mutex::new(None),
+        }
+    }
+
+    /// Gets the shared connection, opening it if needed
+    ///
+    /// # Returns
+    /// The websocket client, or a PubsubClientError if connecting fails
+    pub async fn client(&self) -> Result<Arc<PubsubClient>, PubsubClientError> {
+        let mut client = self.client.lock().await;
+        if let Some(client) = client.as_ref() {
+            return Ok(client.clone());
+        }
+
+        let connected = Arc::new(PubsubClient::new(&self.ws_url).await?);
+        *client = Some(connected.clone());
+        Ok(connected)
+    }
+
+    /// Forgets a connection that was found closed, unless it was already replaced
+    async fn reset(&self, closed: &Arc<PubsubClient>) {
+        let mut client = self.client.lock().await;
+        if client
+            .as_ref()
+            .is_some_and(|client| Arc::ptr_eq(client, closed))
+        {
+            *client = None;
+        }
+    }
+
+    /// Subscribes to the bonding curve accounts of several mints over the shared connection
+    ///
+    /// # Arguments
+    /// * `config` - Program whose bonding curves to watch
+    /// * `mints` - Mints whose bonding curves to watch
+    /// * `commitment` - Commitment level of the updates
+    ///
+    /// # Returns
+    /// A stream yielding an update every time one of the bonding curves changes, or a ClientError
+    /// if a subscription fails
+    pub async fn subscribe_bonding_curves(
+        &self,
+        config: &ProgramConfig,
+        mints: &[Pubkey],
+        commitment: CommitmentConfig,
+    ) -> Result<impl Stream<Item = CurveUpdate>, error::ClientError> {
+        let (sender, receiver) = mpsc::unbounded_channel();
+
+        for mint in mints {
+            let bonding_curve = config
+                .bonding_curve_pda(mint)
+                .ok_or(error::ClientError::BondingCurveNotFound)?;
+            let client = self.client().await?;
+            match subscribe_bonding_curve(
+                client.clone(),
+                *mint,
+                bonding_curve,
+                commitment,
+                sender.clone(),
+            )
+            .await
+            {
+                // The shared connection dropped, so reconnect and retry once
+                Err(PubsubClientError::ConnectionClosed(_)) => {
+                    self.reset(&client).await;
+                    let client = self.client().await?;
+                    subscribe_bonding_curve(
+                        client,
+                        *mint,
+                        bonding_curve,
+                        commitment,
+                        sender.clone(),
+                    )
+                    .await?;
+                }
+                result => result?,
+            }
+        }
+
+        Ok(receiver_stream(receiver))
+    }
+}
+
+/// Subscribes to a mint's bonding curve account and forwards its updates from a background task
+///
+/// The task unsubscribes once the receiver closes, leaving the shared connection open.
+async fn subscribe_bonding_curve(
+    client: Arc<PubsubClient>,
+    mint: Pubkey,
+    bonding_curve: Pubkey,
+    commitment: CommitmentConfig,
+    sender: mpsc::UnboundedSender<CurveUpdate>,
+) -> Result<(), PubsubClientError> {
+    let config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(commitment),
+        ..RpcAccountInfoConfig::default()
+    };
+    let (ready, subscribed) = oneshot::channel();
+
+    tokio::spawn(async move {
+        let (mut updates, unsubscribe) =
+            match client.account_subscribe(&bonding_curve, Some(config)).await {
+                Ok(subscription) => subscription,
+                Err(err) => {
+                    let _ = ready.send(Err(err));
+                    return;
+                }
+            };
+        let _ = ready.send(Ok(()));
+
+        loop {
+            tokio::select! {
+                update = updates.next() => match update {
+                    Some(update) => {
+                        if let Some(update) = decode_curve_update(mint, update) {
+                            if sender.send(update).is_err() {
+                                break;
+                            }
+                        }
+                    }
+                    None => break,
+                },
+                _ = sender.closed() => break,
+            }
+        }
+
+        drop(updates);
+        unsubscribe().await;
+    });
+
+    subscribed
+        .await
+        .map_err(|err| PubsubClientError::ConnectionClosed(err.to_string()))?
+}
+
+/// Deserializes a bonding curve account notification, skipping accounts that do not decode
+fn decode_curve_update(mint: Pubkey, update: Response<UiAccount>) -> Option<CurveUpdate> {
+    let data = update.value.data.decode()?;
+    let bonding_curve = BondingCurveAccount::try_from_account_data(&data).ok()?;
+
+    Some(CurveUpdate {
+        mint,
+        slot: update.context.slot,
+        bonding_curve,
+    })
+}
+
+/// Turns the receiving end of a channel into a stream
+fn receiver_stream<T>(receiver: mpsc::UnboundedReceiver<T>) -> impl Stream<Item = T> {
+    stream::unfold(receiver, |mut receiver| async move {
+        receiver.recv().await.map(|item| (item, receiver))
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{
+        constants::accounts::PUMPFUN,
+        events::{CreateEvent, TradeEvent, PROGRAM_DATA},
+    };
+    use base64::{engine::general_purpose::STANDARD, Engine};
+    use borsh::BorshSerialize;
+    use futures::SinkExt;
+    use serde_json::{json, Value};
+    use tokio::{net::TcpListener, task::JoinHandle, time::timeout};
+    use tokio_tungstenite::{accept_async, tungstenite::Message};
+
+    fn encode_log(discriminator: [u8; 8], event: &impl BorshSerialize) -> String {
+        let mut data = discriminator.to_vec();
+        data.extend(borsh::to_vec(event).unwrap());
+        format!("{}{}", PROGRAM_DATA, STANDARD.encode(data))
+    }
+
+    fn get_trade_event(mint: Pubkey, is_buy: bool) -> TradeEvent {
+        TradeEvent {
+            mint_bytes: mint.to_bytes(),
+            sol_amount: 1_000_000,
+            token_amount: 35_000_000_000,
+            is_buy,
+            user_bytes: Pubkey::new_unique().to_bytes(),
+            timestamp: 1_700_000_000,
+            virtual_sol_reserves: 30_001_000_000,
+            virtual_token_reserves: 1_072_965_000_000_000,
+            real_sol_reserves: 1_000_000,
+            real_token_reserves: 792_065_000_000_000,
+        }
+    }
+
+    /// Builds a recorded `logsNotification` message for logs emitted by the program
+    fn logs_notification(slot: u64, signature: &Signature, err: Value, logs: &[String]) -> String {
+        let mut logs = logs.to_vec();
+        logs.insert(0, format!("Program {} invoke [1]", PUMPFUN));
+        logs.push(format!("Program {} success", PUMPFUN));
+        json!({
+            "jsonrpc": "2.0",
+            "method": "logsNotification",
+            "params": {
+                "result": {
+                    "context": { "slot": slot },
+                    "value": { "signature": signature.to_string(), "err": err, "logs": logs }
+                },
+                "subscription": 7
+            }
+        })
+        .to_string()
+    }
+
+    /// Serves one websocket session per entry, replaying its notifications after the client
+    /// subscribes. Every session but the last is closed by the server to force a reconnection.
+    ///
+    /// Returns the websocket URL and a handle resolving to the subscribe requests received.
+    async fn mock_server(sessions: Vec<Vec<String>>) -> (String, JoinHandle<Vec<Value>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let mut requests = Vec::new();
+            let count = sessions.len();
+
+            for (index, notifications) in sessions.into_iter().enumerate() {
+                let (stream, _) = listener.accept().await.unwrap();
+                let mut ws = accept_async(stream).await.unwrap();
+
+                // Acknowledge the subscription
+                while let Some(Ok(message)) = ws.next().await {
+                    if let Message::Text(text) = message {
+                        let request: Value = serde_json::from_str(&text).unwrap();
+                        let response = json!({"jsonrpc": "2.0", "result": 7, "id": request["id"]});
+                        ws.send(Message::Text(response.to_string())).await.unwrap();
+                        requests.push(request);
+                        break;
+                    }
+                }
+
+                for notification in notifications {
+                    ws.send(Message::Text(notification)).await.unwrap();
+                }
+
+                if index + 1 < count {
+                    ws.close(None).await.unwrap();
+                } else {
+                    // Wait for the client to go away
+                    while let Some(Ok(_)) = ws.next().await {}
+                }
+            }
+
+            requests
+        });
+
+        (url, handle)
+    }
+
+    #[test]
+    fn test_event_filter() {
+        let mint = Pubkey::new_unique();
+        let trade = PumpFunEvent::Trade(get_trade_event(mint, true));
+        let other = PumpFunEvent::Trade(get_trade_event(Pubkey::new_unique(), true));
+
+        assert!(EventFilter::default().matches(&trade));
+
+        let filter = EventFilter {
+            mints: vec![mint],
+            kinds: vec![],
+        };
+        assert!(filter.matches(&trade));
+        assert!(!filter.matches(&other));
+
+        let filter = EventFilter {
+            mints: vec![],
+            kinds: vec![EventKind::Create],
+        };
+        assert!(!filter.matches(&trade));
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_reconnects() {
+        let mint = Pubkey::new_unique();
+        let create = CreateEvent {
+            name: "Test Token".to_string(),
+            symbol: "TEST".to_string(),
+            uri: "https://example.com".to_string(),
+            mint_bytes: mint.to_bytes(),
+            bonding_curve_bytes: Pubkey::new_unique().to_bytes(),
+            user_bytes: Pubkey::new_unique().to_bytes(),
+        };
+        let buy = get_trade_event(mint, true);
+        let sell = get_trade_event(mint, false);
+        let other = get_trade_event(Pubkey::new_unique(), true);
+        let signatures: Vec<Signature> = (0..4).map(|_| Signature::new_unique()).collect();
+
+        let sessions = vec![
+            vec![
+                logs_notification(
+                    1,
+                    &signatures[0],
+                    Value::Null,
+                    &[
+                        encode_log(CreateEvent::DISCRIMINATOR, &create),
+                        encode_log(TradeEvent::DISCRIMINATOR, &buy),
+                    ],
+                ),
+                // Events of other mints are filtered out
+                logs_notification(
+                    2,
+                    &signatures[1],
+                    Value::Null,
+                    &[encode_log(TradeEvent::DISCRIMINATOR, &other)],
+                ),
+            ],
+            vec![
+                // Events of failed transactions are skipped
+                logs_notification(
+                    3,
+                    &signatures[2],
+                    json!({"InstructionError": [0, {"Custom": 6003}]}),
+                    &[encode_log(TradeEvent::DISCRIMINATOR, &sell)],
+                ),
+                logs_notification(
+                    4,
+                    &signatures[3],
+                    Value::Null,
+                    &[encode_log(TradeEvent::DISCRIMINATOR, &sell)],
+                ),
+            ],
+        ];
+        let (url, server) = mock_server(sessions).await;
+
+        let filter = EventFilter {
+            mints: vec![mint],
+            kinds: vec![],
+        };
+        let stream = subscribe_events(
+            url,
+            ProgramConfig::default(),
+            CommitmentConfig::confirmed(),
+            filter,
+        )
+        .await
+        .unwrap();
+        let notifications: Vec<EventNotification> =
+            timeout(Duration::from_secs(10), stream.take(3).collect())
+                .await
+                .unwrap();
+
+        assert_eq!(
+            notifications,
+            vec![
+                EventNotification {
+                    signature: signatures[0],
+                    slot: 1,
+                    event: PumpFunEvent::Create(create),
+                },
+                EventNotification {
+                    signature: signatures[0],
+                    slot: 1,
+                    event: PumpFunEvent::Trade(buy),
+                },
+                EventNotification {
+                    signature: signatures[3],
+                    slot: 4,
+                    event: PumpFunEvent::Trade(sell),
+                },
+            ]
+        );
+
+        // The subscription was renewed on the second connection
+        let requests = timeout(Duration::from_secs(10), server)
+            .await
+            .unwrap()
+            .unwrap();
+        assert_eq!(requests.len(), 2);
+        for request in requests {
+            assert_eq!(request["method"], "logsSubscribe");
+            assert_eq!(request["params"][0]["mentions"][0], PUMPFUN.to_string());
+            assert_eq!(request["params"][1]["commitment"], "confirmed");
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_events_connection_error() {
+        // Reserve a port and close it so nothing is listening
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+        drop(listener);
+
+        let result = subscribe_events(
+            url,
+            ProgramConfig::default(),
+            CommitmentConfig::confirmed(),
+            EventFilter::default(),
+        )
+        .await;
+        assert!(matches!(
+            result,
+            Err(error::ClientError::PubsubClientError(_))
+        ));
+    }
+
+    /// Builds a recorded `accountNotification` message for a bonding curve
+    fn account_notification(subscription: u64, slot: u64, curve: &BondingCurveAccount) -> String {
+        json!({
+            "jsonrpc": "2.0",
+            "method": "accountNotification",
+            "params": {
+                "result": {
+                    "context": { "slot": slot },
+                    "value": {
+                        "lamports": 1_000_000,
+                        "data": [STANDARD.encode(borsh::to_vec(curve).unwrap()), "base64"],
+                        "owner": PUMPFUN.to_string(),
+                        "executable": false,
+                        "rentEpoch": 0
+                    }
+                },
+                "subscription": subscription
+            }
+        })
+        .to_string()
+    }
+
+    /// Serves a single websocket session that acknowledges account subscriptions and replays the
+    /// updates of each subscribed account once all of them are subscribed
+    ///
+    /// Returns the websocket URL and a handle resolving to the requests received.
+    async fn mock_account_server(
+        updates: Vec<(Pubkey, u64, BondingCurveAccount)>,
+        subscriptions: usize,
+    ) -> (String, JoinHandle<Vec<Value>>) {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let url = format!("ws://{}", listener.local_addr().unwrap());
+
+        let handle = tokio::spawn(async move {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = accept_async(stream).await.unwrap();
+            let mut accounts = Vec::new();
+            let mut requests = Vec::new();
+
+            while let Some(Ok(message)) = ws.next().await {
+                let Message::Text(text) = message else {
+                    continue;
+                };
+                let request: Value = serde_json::from_str(&text).unwrap();
+                let result = if request["method"] == "accountSubscribe" {
+                    accounts.push(request["params"][0].as_str().unwrap().to_string());
+                    json!(accounts.len())
+                } else {
+                    json!(true)
+                };
+                let response = json!({"jsonrpc": "2.0", "result": result, "id": request["id"]});
+                ws.send(Message::Text(response.to_string())).await.unwrap();
+                requests.push(request);
+
+                if request_is_last_subscription(&requests, subscriptions) {
+                    for (account, slot, curve) in &updates {
+                        let subscription = accounts
+                            .iter()
+                            .position(|subscribed| *subscribed == account.to_string())
+                            .unwrap() as u64
+                            + 1;
+                        let notification = account_notification(subscription, *slot, curve);
+                        ws.send(Message::Text(notification)).await.unwrap();
+                    }
+                }
+            }
+
+            requests
+        });
+
+        (url, handle)
+    }
+
+    fn request_is_last_subscription(requests: &[Value], subscriptions: usize) -> bool {
+        requests
+            .iter()
+            .filter(|request| request["method"] == "accountSubscribe")
+            .count()
+            == subscriptions
+            && requests.last().unwrap()["method"] == "accountSubscribe"
+    }
+
+    #[tokio::test]
+    async fn test_subscribe_bonding_curves_shares_connection() {
+        // Curves of a program deployed under a custom address
+        let config = ProgramConfig::new(Pubkey::new_unique());
+        let mints = [Pubkey::new_unique(), Pubkey::new_unique()];
+        let curves: Vec<Pubkey> = mints
+            .iter()
+            .map(|mint| config.bonding_curve_pda(mint).unwrap())
+            .collect();
+        let curve = BondingCurveAccount::new(
+            u64::from_le_bytes(BondingCurveAccount::DISCRIMINATOR),
+            1_073_000_000_000_000,
+            30_000_000_000,
+            793_100_000_000_000,
+            0,
+            1_000_000_000_000_000,
+            false,
+        );
+        let mut bought = curve.clone();
+        bought.apply_buy(35_000_000_000, 100).unwrap();
+
+        let updates = vec![
+            (curves[0], 10, curve.clone()),
+            (curves[1], 11, curve.clone()),
+            (curves[0], 12, bought.clone()),
+        ];
+        let (url, server) = mock_account_server(updates, 2).await;
+
+        let client = SharedPubsubClient::new(url);
+        let first = client
+            .subscribe_bonding_curves(&config, &mints[..1], CommitmentConfig::confirmed())
+            .await
+            .unwrap();
+        let second = client
+            .subscribe_bonding_curves(&config, &mints[1..], CommitmentConfig::confirmed())
+            .await
+            .unwrap();
+
+        let first: Vec<CurveUpdate> = timeout(Duration::from_secs(10), first.take(2).collect())
+            .await
+            .unwrap();
+        assert_eq!(first.len(), 2);
+        assert!(first.iter().all(|update| update.mint == mints[0]));
+        assert_eq!(first[0].slot, 10);
+        assert_eq!(
+            first[1].bonding_curve.virtual_token_reserves,
+            bought.virtual_token_reserves
+        );
+        assert_eq!(first[1].slot, 12);
+
+        let second: Vec<CurveUpdate> = timeout(Duration::from_secs(10), second.take(1).collect())
+            .await
+            .unwrap();
+        assert_eq!(second[0].mint, mints[1]);
+        assert_eq!(second[0].slot, 11);
+
+        // Both subscriptions used one connection and were unsubscribed once their streams ended
+        drop(client);
+        let requests = timeout(Duration::from_secs(10), server)
+            .await
+            .unwrap()
+            .unwrap();
+        let methods: Vec<&str> = requests
+            .iter()
+            .map(|request| request["method"].as_str().unwrap())
+            .collect();
+        assert_eq!(
+            methods,
+            vec![
+                "accountSubscribe",
+                "accountSubscribe",
+                "accountUnsubscribe",
+                "accountUnsubscribe"
+            ]
+        );
+        assert_eq!(requests[0]["params"][0], curves[0].to_string());
+        assert_eq!(requests[0]["params"][1]["encoding"], "base64");
+        assert_eq!(requests[1]["params"][0], curves[1].to_string());
+    }
+}
@@ -73,7 +73,7 @@ pub struct CreateTokenMetadata {
 /// # Returns
 ///
 /// Returns a `Result` containing the `TokenMetadataResponse` with IPFS locations on success,
-/// or an error if the upload fails.
+/// or an error if the upload fails. A rate limited upload fails with `ClientError::RateLimitExceeded`.
 ///
 /// # Examples
 ///
@@ -160,6 +160,9 @@ pub async fn create_token_metadata(
 
     // Send request and print response
     let mut response = client.send_async(request).await?;
+    if response.status() == isahc::http::StatusCode::TOO_MANY_REQUESTS {
+        return Err(Box::new(crate::error::ClientError::RateLimitExceeded));
+    }
     let text = response.text().await?;
     let json: TokenMetadataResponse = serde_json::from_str(&text)?;
 